@@ -0,0 +1,290 @@
+# Shown on the language button in every language's own words
+language-name = English
+
+## Menus
+
+menu-main-menu = Main Menu
+menu-play = Play
+menu-training = Training
+menu-time-attack = Time Attack
+menu-endless = Endless
+menu-daily-challenge = Daily Challenge
+menu-level-select = Level Select
+menu-custom-levels = Custom Levels
+menu-level-editor = Level Editor
+menu-high-scores = High Scores
+menu-settings = Settings
+menu-quit = Quit
+menu-next-level = Next Level
+menu-replay-level = Replay Level
+menu-play-again = Play Again
+menu-back-to-editor = Back to Editor
+
+level-select-heading = Select a Level
+
+## Settings
+
+settings-heading = Settings
+settings-reduced-motion = Reduced Motion: { $value ->
+    [on] On
+   *[off] Off
+}
+settings-screen-flash = Screen Flash: { $value ->
+    [on] On
+   *[off] Off
+}
+settings-particles = Particles: { $quality ->
+    [high] High
+    [low] Low
+   *[off] Off
+}
+settings-chain-preview = Chain Preview: { $value ->
+    [on] On
+   *[off] Off
+}
+settings-danger-zones = Danger Zones: { $value ->
+    [on] On
+   *[off] Off
+}
+settings-color-palette = Colors: { $palette ->
+    [deuteranopia] Deuteranopia
+    [protanopia] Protanopia
+    [tritanopia] Tritanopia
+    [high-contrast] High Contrast
+   *[standard] Standard
+}
+settings-wave-markers = Wave Markers: { $value ->
+    [on] On
+   *[off] Off
+}
+settings-language = Language: { language-name }
+
+## Gameplay
+
+ready = Ready?
+
+enemy-class-base = Base
+enemy-class-defender-one = Defender One
+enemy-class-defender-two = Defender Two
+enemy-class-defender-three = Defender Three
+enemy-class-land = Land
+enemy-class-shadow = Shadow
+enemy-class-wall = Wall
+
+## Level complete
+
+level-complete-heading = Level Complete!
+level-complete-keep-going = Good job! Keep going?
+level-complete-replay = Good job! Replay?
+level-complete-playtest = It can be beaten! Back to the editor?
+game-complete-heading = Congratulations!!!
+game-complete-text = You beat the game! Play again?
+pack-complete-heading = Pack Complete!
+pack-complete-text = You cleared every level in the pack! Play it again?
+time-attack-complete-heading = Time Attack Complete!
+time-attack-complete-text = You beat the clock! Go for a faster time?
+run-over-heading = Run Over!
+run-over-text = Endless runs end with the first loss. Go again?
+almost-heading = Almost!
+almost-text = You got the enemy, but their explosions got you too! You can do it!
+player-lost-heading = Player Lost!
+player-lost-text = Nice try! Go again?
+stats-error-heading = Error: level stats not complete
+stats-error-text = Sorry, something went wrong!
+
+level-complete-enemies-destroyed = { $total ->
+    [one] Level Enemy Destroyed: { $destroyed }/{ $total }
+   *[other] Level Enemies Destroyed: { $destroyed }/{ $total }
+}
+level-complete-time = in { $secs } secs
+level-complete-score = Score: { $score } (x{ $multiplier } chain)
+level-complete-stars = Stars: { $stars }
+level-complete-run-score = Run Score: { $score }
+level-complete-levels-cleared = { $count ->
+    [one] { $count } Level Cleared
+   *[other] Levels Cleared: { $count }
+}
+level-complete-campaign-stars = Campaign Stars: { $earned }/{ $available }
+new-record = { $kind ->
+    [level] New Level Record!
+   *[run] New Run Record!
+}
+new-high-score = { $kind ->
+    [level] New Level High Score #{ $rank }!
+   *[run] New Run High Score #{ $rank }!
+}
+
+## Loading
+
+loading-heading = Loading
+loading-failed-heading = Loading Failed
+loading-failed-files = These files could not be loaded:
+loading-failed-text = The game can still be played, but some things will be missing.
+menu-continue-anyway = Continue Anyway
+
+## High scores
+
+high-scores-heading = High Scores
+high-scores-next-table = Next Table
+high-scores-previous-table = Previous Table
+high-scores-player-name = Name: { $name }{ $cursor }
+high-scores-run-title = { $mode ->
+    [training] Training Run
+    [endless] Endless Run
+   *[game] Campaign Run
+}
+high-scores-daily-title = Daily Challenge { $date }
+high-scores-empty = No scores yet
+high-scores-column-name = Name
+high-scores-column-score = Score
+high-scores-column-time = Time
+high-scores-column-date = Date
+high-scores-column-difficulty = Difficulty
+difficulty = { $difficulty ->
+    [training] Training
+   *[normal] Normal
+}
+
+## Custom levels
+
+custom-levels-heading = Custom Levels
+custom-levels-none-found = No level packs found
+custom-levels-mods-dir = Add level packs to { $dir }
+custom-levels-no-data-dir = No data directory available for level packs
+custom-levels-desktop-only = Level packs can only be added to the desktop version
+custom-levels-problems = Some packs could not be loaded:
+custom-levels-pack = { $name }  { $cleared }/{ $total } cleared  { $earned }/{ $available } stars
+
+## Daily challenge
+
+daily-challenge-heading = Daily Challenge
+daily-challenge-practice = Practice
+daily-result = { $outcome ->
+    [cleared] Cleared { $score } { $secs }s
+    [failed] Failed
+    [unfinished] Unfinished
+   *[none] Not attempted yet
+}
+daily-today = Today: { daily-result }
+daily-history-row = { $date }  { daily-result }
+daily-history-empty = No daily challenges played yet
+daily-attempt-recorded = Today's result has been recorded
+daily-attempt-practice = Practice run - only the first attempt counts
+
+## Time attack
+
+time-attack-heading = Time Attack
+time-attack-summary = Time Attack: { $secs } secs
+time-attack-new-best = New Personal Best!
+time-attack-best = Personal Best: { $secs } secs
+
+## Heads up display
+
+hud-multiplier = x{ $multiplier }
+level-goal-par = Par: { $secs } secs
+level-goal = Goal: { $goal ->
+    [chain] Reach a x{ $multiplier } chain multiplier
+    [score] Score at least { $score }
+   *[defenders] Destroy every Defender yourself
+}
+
+## Level editor
+
+editor-help =
+    1 Base  2-4 Defenders  5 Shadow
+    6 Land  7 Wall
+    Click: place or select
+    Drag: move piece or start
+    Right click / Del: delete
+    Wheel: resize (Shift: width)
+    Wheel with nothing selected: scroll
+    Arrows: move (Shift: resize)
+    Tab: next piece
+    S: start at cursor  G: snap
+    PgUp/PgDn: scroll  H: taller (Shift: shorter)
+    N: name  M: notes
+    F2: save  F3: open  F4: new
+    P: playtest  Esc: main menu
+editor-placing = Placing: { $class }
+editor-snap = Snap: { $value ->
+    [on] On
+   *[off] Off
+}
+editor-start = Start: { $x }, { $y }
+editor-selected-nothing = Selected: Nothing
+editor-selected = Selected: { $class } at { $x }, { $y }
+editor-selected-sized = Selected: { $class } at { $x }, { $y } size { $width }x{ $height }
+editor-welcome = F3 opens an existing level, F4 starts a new one
+editor-fixed-size = That piece has a fixed size
+editor-stage-height = Stage is { $height } high
+editor-saved = Saved as { $key }
+editor-opened = Opened { $name }
+editor-new-level = Started a new level
+editor-problem = { $kind ->
+    [error] Problem
+   *[note] Note
+}: { $problem }
+editor-no-problems = No problems found
+editor-notes-hint = Press M to write notes
+
+level-problem-out-of-bounds = Enemy { $index } at { $x }, { $y } is outside the stage
+level-problem-start-inside-wall = Start position is inside wall { $index } at { $x }, { $y }
+level-problem-start-inside-land = Start position is inside land { $index } at { $x }, { $y }
+level-problem-overlapping-defenders = Enemy { $index } at { $x }, { $y } overlaps defender { $other }
+level-problem-no-required-enemies = No enemy needs to be destroyed
+level-problem-background-outside-assets = Background { $path } is not inside assets, the generic one is used
+
+## Built in levels, with the notes as an attribute
+
+level-name-enemy-bases = Enemy Bases
+    .notes =
+        Fire at the Enemy Base to destroy them.
+
+        You aren't safe until you have avoided their explosion.
+level-name-defenders = Defenders
+    .notes =
+        Enemy Bases can be guarded by Defenders.
+
+        You can destroy the Enemy Defenders or go straight for the Enemy Base.
+        Destroying a base will cause a chain reaction which destroys Enemy Defenders after a short delay.
+level-name-additional-waves = Additional Waves
+    .notes =
+        Icons inside Enemy Defenders indicate which defense Wave they are in.
+
+        Defender Waves will explode in order.
+level-name-shadow = Shadow
+    .notes =
+        Outlined Defenders are invulnerable to projectiles, though you can still shoot past them.
+
+        They will explode before Defenders.
+level-name-land = Land
+    .notes =
+        Enemy Bases control Land.
+
+        You can't destroy it and it can't destroy you.
+        However, it will be destroyed in the chain reaction (after defenders) and you must survive its explosions to pass the level.
+level-name-enemy-walls = Enemy Walls
+    .notes =
+        You can't destroy Enemy Walls and your projectiles have no effect on them.
+
+        Fortunately, they don't explode.
+level-name-off-the-beaten-path = Off The Beaten Path
+level-name-off-the-beaten-path-2 = Off The Beaten Path 2
+level-name-off-the-beaten-path-3 = Off The Beaten Path 3
+level-name-corridor-shooter = Corridor Shooter
+level-name-corridor-shooter-2 = Corridor Shooter 2
+level-name-in-yer-face = In Yer Face
+level-name-in-yer-face-2 = In Yer Face 2
+level-name-in-yer-face-3 = In Yer Face 3
+level-name-choose-your-destiny = Choose Your Destiny
+level-name-choose-your-destiny-2 = Choose Your Destiny 2
+level-name-endless = Endless
+    .notes =
+        Levels keep coming until you lose one.
+
+        Every level is tougher than the last, but there is always a way through.
+level-name-daily-challenge = Daily Challenge
+    .notes =
+        A new level every day, the same for everyone on the same date.
+
+        Only your first attempt counts - make it a good one!
@@ -0,0 +1,290 @@
+# Shown on the language button in every language's own words
+language-name = Español
+
+## Menus
+
+menu-main-menu = Menú Principal
+menu-play = Jugar
+menu-training = Entrenamiento
+menu-time-attack = Contrarreloj
+menu-endless = Sin Fin
+menu-daily-challenge = Desafío Diario
+menu-level-select = Elegir Nivel
+menu-custom-levels = Niveles Personalizados
+menu-level-editor = Editor de Niveles
+menu-high-scores = Récords
+menu-settings = Ajustes
+menu-quit = Salir
+menu-next-level = Siguiente Nivel
+menu-replay-level = Repetir Nivel
+menu-play-again = Jugar Otra Vez
+menu-back-to-editor = Volver al Editor
+
+level-select-heading = Elige un Nivel
+
+## Settings
+
+settings-heading = Ajustes
+settings-reduced-motion = Movimiento Reducido: { $value ->
+    [on] Sí
+   *[off] No
+}
+settings-screen-flash = Destello de Pantalla: { $value ->
+    [on] Sí
+   *[off] No
+}
+settings-particles = Partículas: { $quality ->
+    [high] Altas
+    [low] Bajas
+   *[off] No
+}
+settings-chain-preview = Vista de Cadena: { $value ->
+    [on] Sí
+   *[off] No
+}
+settings-danger-zones = Zonas de Peligro: { $value ->
+    [on] Sí
+   *[off] No
+}
+settings-color-palette = Colores: { $palette ->
+    [deuteranopia] Deuteranopía
+    [protanopia] Protanopía
+    [tritanopia] Tritanopía
+    [high-contrast] Alto Contraste
+   *[standard] Estándar
+}
+settings-wave-markers = Marcas de Oleada: { $value ->
+    [on] Sí
+   *[off] No
+}
+settings-language = Idioma: { language-name }
+
+## Gameplay
+
+ready = ¿Listo?
+
+enemy-class-base = Base
+enemy-class-defender-one = Defensor Uno
+enemy-class-defender-two = Defensor Dos
+enemy-class-defender-three = Defensor Tres
+enemy-class-land = Terreno
+enemy-class-shadow = Sombra
+enemy-class-wall = Muro
+
+## Level complete
+
+level-complete-heading = ¡Nivel Completado!
+level-complete-keep-going = ¡Bien hecho! ¿Seguimos?
+level-complete-replay = ¡Bien hecho! ¿Otra vez?
+level-complete-playtest = ¡Se puede superar! ¿Volver al editor?
+game-complete-heading = ¡¡¡Enhorabuena!!!
+game-complete-text = ¡Has terminado el juego! ¿Jugar otra vez?
+pack-complete-heading = ¡Paquete Completado!
+pack-complete-text = ¡Has superado todos los niveles del paquete! ¿Otra vez?
+time-attack-complete-heading = ¡Contrarreloj Completada!
+time-attack-complete-text = ¡Has vencido al reloj! ¿Buscas un tiempo mejor?
+run-over-heading = ¡Fin de la Partida!
+run-over-text = Las partidas sin fin acaban con la primera derrota. ¿Otra vez?
+almost-heading = ¡Casi!
+almost-text = Acabaste con el enemigo, ¡pero sus explosiones acabaron contigo! ¡Tú puedes!
+player-lost-heading = ¡Has Perdido!
+player-lost-text = ¡Buen intento! ¿Otra vez?
+stats-error-heading = Error: estadísticas del nivel incompletas
+stats-error-text = ¡Lo sentimos, algo ha fallado!
+
+level-complete-enemies-destroyed = { $total ->
+    [one] Enemigo Destruido: { $destroyed }/{ $total }
+   *[other] Enemigos Destruidos: { $destroyed }/{ $total }
+}
+level-complete-time = en { $secs } s
+level-complete-score = Puntos: { $score } (cadena x{ $multiplier })
+level-complete-stars = Estrellas: { $stars }
+level-complete-run-score = Puntos de la Partida: { $score }
+level-complete-levels-cleared = { $count ->
+    [one] { $count } Nivel Superado
+   *[other] Niveles Superados: { $count }
+}
+level-complete-campaign-stars = Estrellas de la Campaña: { $earned }/{ $available }
+new-record = { $kind ->
+    [level] ¡Nuevo Récord del Nivel!
+   *[run] ¡Nuevo Récord de Partida!
+}
+new-high-score = { $kind ->
+    [level] ¡Nueva Puntuación del Nivel n.º { $rank }!
+   *[run] ¡Nueva Puntuación de Partida n.º { $rank }!
+}
+
+## Loading
+
+loading-heading = Cargando
+loading-failed-heading = Error al Cargar
+loading-failed-files = No se han podido cargar estos archivos:
+loading-failed-text = Se puede seguir jugando, pero faltarán algunas cosas.
+menu-continue-anyway = Continuar de Todos Modos
+
+## High scores
+
+high-scores-heading = Récords
+high-scores-next-table = Tabla Siguiente
+high-scores-previous-table = Tabla Anterior
+high-scores-player-name = Nombre: { $name }{ $cursor }
+high-scores-run-title = { $mode ->
+    [training] Partida de Entrenamiento
+    [endless] Partida Sin Fin
+   *[game] Partida de Campaña
+}
+high-scores-daily-title = Desafío Diario { $date }
+high-scores-empty = Aún no hay puntuaciones
+high-scores-column-name = Nombre
+high-scores-column-score = Puntos
+high-scores-column-time = Tiempo
+high-scores-column-date = Fecha
+high-scores-column-difficulty = Dificultad
+difficulty = { $difficulty ->
+    [training] Entrenamiento
+   *[normal] Normal
+}
+
+## Custom levels
+
+custom-levels-heading = Niveles Personalizados
+custom-levels-none-found = No se han encontrado paquetes de niveles
+custom-levels-mods-dir = Añade paquetes de niveles a { $dir }
+custom-levels-no-data-dir = No hay carpeta de datos para los paquetes de niveles
+custom-levels-desktop-only = Los paquetes de niveles solo se pueden añadir en la versión de escritorio
+custom-levels-problems = No se han podido cargar algunos paquetes:
+custom-levels-pack = { $name }  { $cleared }/{ $total } superados  { $earned }/{ $available } estrellas
+
+## Daily challenge
+
+daily-challenge-heading = Desafío Diario
+daily-challenge-practice = Practicar
+daily-result = { $outcome ->
+    [cleared] Superado { $score } { $secs } s
+    [failed] Fallido
+    [unfinished] Sin terminar
+   *[none] Aún sin intentar
+}
+daily-today = Hoy: { daily-result }
+daily-history-row = { $date }  { daily-result }
+daily-history-empty = Aún no has jugado ningún desafío diario
+daily-attempt-recorded = El resultado de hoy ha quedado registrado
+daily-attempt-practice = Partida de práctica: solo cuenta el primer intento
+
+## Time attack
+
+time-attack-heading = Contrarreloj
+time-attack-summary = Contrarreloj: { $secs } s
+time-attack-new-best = ¡Nueva Mejor Marca Personal!
+time-attack-best = Mejor Marca Personal: { $secs } s
+
+## Heads up display
+
+hud-multiplier = x{ $multiplier }
+level-goal-par = Par: { $secs } s
+level-goal = Objetivo: { $goal ->
+    [chain] Consigue un multiplicador de cadena x{ $multiplier }
+    [score] Consigue al menos { $score } puntos
+   *[defenders] Destruye tú mismo a todos los Defensores
+}
+
+## Level editor
+
+editor-help =
+    1 Base  2-4 Defensores  5 Sombra
+    6 Terreno  7 Muro
+    Clic: colocar o seleccionar
+    Arrastrar: mover pieza o salida
+    Clic derecho / Supr: borrar
+    Rueda: tamaño (Mayús: anchura)
+    Rueda sin selección: desplazar
+    Flechas: mover (Mayús: tamaño)
+    Tab: siguiente pieza
+    S: salida en el cursor  G: rejilla
+    RePág/AvPág: desplazar  H: más alto (Mayús: más bajo)
+    N: nombre  M: notas
+    F2: guardar  F3: abrir  F4: nuevo
+    P: probar  Esc: menú principal
+editor-placing = Colocando: { $class }
+editor-snap = Rejilla: { $value ->
+    [on] Sí
+   *[off] No
+}
+editor-start = Salida: { $x }, { $y }
+editor-selected-nothing = Seleccionado: Nada
+editor-selected = Seleccionado: { $class } en { $x }, { $y }
+editor-selected-sized = Seleccionado: { $class } en { $x }, { $y } tamaño { $width }x{ $height }
+editor-welcome = F3 abre un nivel existente, F4 empieza uno nuevo
+editor-fixed-size = Esa pieza tiene un tamaño fijo
+editor-stage-height = El escenario mide { $height } de alto
+editor-saved = Guardado como { $key }
+editor-opened = Abierto { $name }
+editor-new-level = Nivel nuevo empezado
+editor-problem = { $kind ->
+    [error] Problema
+   *[note] Nota
+}: { $problem }
+editor-no-problems = No se han encontrado problemas
+editor-notes-hint = Pulsa M para escribir notas
+
+level-problem-out-of-bounds = El enemigo { $index } en { $x }, { $y } está fuera del escenario
+level-problem-start-inside-wall = La salida está dentro del muro { $index } en { $x }, { $y }
+level-problem-start-inside-land = La salida está dentro del terreno { $index } en { $x }, { $y }
+level-problem-overlapping-defenders = El enemigo { $index } en { $x }, { $y } se solapa con el defensor { $other }
+level-problem-no-required-enemies = No hay ningún enemigo que destruir
+level-problem-background-outside-assets = El fondo { $path } no está dentro de assets, se usa el genérico
+
+## Built in levels, with the notes as an attribute
+
+level-name-enemy-bases = Bases Enemigas
+    .notes =
+        Dispara a la Base Enemiga para destruirla.
+
+        No estás a salvo hasta que hayas esquivado su explosión.
+level-name-defenders = Defensores
+    .notes =
+        Las Bases Enemigas pueden estar protegidas por Defensores.
+
+        Puedes destruir a los Defensores Enemigos o ir directo a por la Base Enemiga.
+        Destruir una base provoca una reacción en cadena que destruye a los Defensores Enemigos tras un breve retraso.
+level-name-additional-waves = Oleadas Adicionales
+    .notes =
+        Los iconos dentro de los Defensores Enemigos indican a qué Oleada defensiva pertenecen.
+
+        Las Oleadas de Defensores explotan en orden.
+level-name-shadow = Sombra
+    .notes =
+        Los Defensores con contorno son invulnerables a los proyectiles, aunque puedes disparar a través de ellos.
+
+        Explotan antes que los Defensores.
+level-name-land = Terreno
+    .notes =
+        Las Bases Enemigas controlan el Terreno.
+
+        No puedes destruirlo y él no puede destruirte.
+        Sin embargo, se destruye en la reacción en cadena (después de los defensores) y debes sobrevivir a sus explosiones para superar el nivel.
+level-name-enemy-walls = Muros Enemigos
+    .notes =
+        No puedes destruir los Muros Enemigos y tus proyectiles no les afectan.
+
+        Por suerte, no explotan.
+level-name-off-the-beaten-path = Fuera de lo Común
+level-name-off-the-beaten-path-2 = Fuera de lo Común 2
+level-name-off-the-beaten-path-3 = Fuera de lo Común 3
+level-name-corridor-shooter = Tirador de Pasillo
+level-name-corridor-shooter-2 = Tirador de Pasillo 2
+level-name-in-yer-face = En Toda la Cara
+level-name-in-yer-face-2 = En Toda la Cara 2
+level-name-in-yer-face-3 = En Toda la Cara 3
+level-name-choose-your-destiny = Elige tu Destino
+level-name-choose-your-destiny-2 = Elige tu Destino 2
+level-name-endless = Sin Fin
+    .notes =
+        Los niveles siguen llegando hasta que pierdas uno.
+
+        Cada nivel es más difícil que el anterior, pero siempre hay una salida.
+level-name-daily-challenge = Desafío Diario
+    .notes =
+        Un nivel nuevo cada día, el mismo para todos en la misma fecha.
+
+        Solo cuenta tu primer intento: ¡que sea bueno!
@@ -0,0 +1,235 @@
+// Compares frame times with and without entity pooling while corridor_01 is played headless with
+// the fire button held, restarting the level whenever it ends.
+//
+//     cargo bench --bench pooling -- [--frames N]
+//
+// Pooling should mostly show up as a smaller spread, since the spikes come from spawning and
+// despawning scene instances and colliders rather than from the average frame.
+
+use std::time::{Duration, Instant};
+
+use avian2d::prelude::*;
+use bevy::{
+    asset::AssetMetaCheck,
+    log::{Level, LogPlugin},
+    prelude::*,
+    render::{RenderPlugin, settings::WgpuSettings},
+    time::TimeUpdateStrategy,
+    window::ExitCondition,
+    winit::WinitPlugin,
+};
+use bevy_flair::prelude::FlairPlugin;
+use bevy_skein::SkeinPlugin;
+use maxx_obliterate::{
+    app_state::{AppState, AppStatePlugin},
+    gameplay::{
+        collisions::CollisionPlugin,
+        enemy::EnemyPlugin,
+        energy::EnergyPlugin,
+        explosion::ExplosionPlugin,
+        game_run::{GameRun, GameRunPlugin},
+        high_scores::NewRecords,
+        level::{LevelPlugin, LevelState, LevelStats},
+        player::{Player, PlayerOwnedWeapons, PlayerPlugin},
+        pool::{EntityPool, PoolPlugin},
+        rating::RatingPlugin,
+        score::ScorePlugin,
+        stage::StagePlugin,
+        stage_bounds::StageBoundsPlugin,
+    },
+    levels::game::corridor_01,
+    loading::LoadingPlugin,
+};
+
+// Matches the default fixed timestep so every update runs exactly one FixedUpdate
+const TICK: Duration = Duration::from_micros(15_625);
+const MAX_SETUP_UPDATES: u32 = 600;
+const WARMUP_FRAMES: usize = 120;
+const DEFAULT_FRAMES: usize = 3000;
+
+fn headless_app(pooling: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(AssetPlugin {
+                meta_check: AssetMetaCheck::Never,
+                ..default()
+            })
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..default()
+            })
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..default()
+                }
+                .into(),
+                ..default()
+            })
+            .set(LogPlugin {
+                level: Level::ERROR,
+                ..default()
+            })
+            .disable::<WinitPlugin>(),
+    );
+    app.add_plugins((SkeinPlugin::default(), FlairPlugin));
+    app.add_plugins(PhysicsPlugins::default())
+        .insert_resource(Gravity(Vec2::ZERO));
+    app.add_plugins((
+        AppStatePlugin,
+        CollisionPlugin,
+        EnemyPlugin,
+        EnergyPlugin,
+        ExplosionPlugin,
+        GameRunPlugin,
+        LevelPlugin,
+        LoadingPlugin,
+        PlayerPlugin,
+        PoolPlugin,
+        RatingPlugin,
+        ScorePlugin,
+        StagePlugin,
+        StageBoundsPlugin,
+    ))
+    .init_resource::<NewRecords>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(TICK));
+    app.finish();
+    app.cleanup();
+    app.world_mut().resource_mut::<EntityPool>().enabled = pooling;
+    app
+}
+
+fn update_until(app: &mut App, mut done: impl FnMut(&mut World) -> bool) -> Result<(), String> {
+    for _ in 0..MAX_SETUP_UPDATES {
+        if done(app.world_mut()) {
+            return Ok(());
+        }
+        app.update();
+    }
+    Err("Timed out waiting for the level to be ready".into())
+}
+
+fn start_level(app: &mut App) -> Result<(), String> {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::Space);
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::ResetGameplay);
+    update_until(app, |world| {
+        world
+            .get_resource::<State<LevelState>>()
+            .is_some_and(|state| state.get() == &LevelState::Ready)
+    })?;
+    update_until(app, |world| {
+        world
+            .query_filtered::<(), (With<Player>, With<PlayerOwnedWeapons>)>()
+            .iter(world)
+            .next()
+            .is_some()
+    })?;
+    app.world_mut()
+        .resource_mut::<NextState<LevelState>>()
+        .set(LevelState::Playing);
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Space);
+    Ok(())
+}
+
+fn level_over(world: &mut World) -> bool {
+    world
+        .query::<&LevelStats>()
+        .iter(world)
+        .next()
+        .is_none_or(|level_stats| level_stats.success.is_some())
+}
+
+struct FrameStats {
+    mean: f64,
+    std_dev: f64,
+    p99: f64,
+    max: f64,
+}
+
+impl FrameStats {
+    fn new(mut millis: Vec<f64>) -> Self {
+        millis.sort_by(f64::total_cmp);
+        let mean = millis.iter().sum::<f64>() / millis.len() as f64;
+        let variance =
+            millis.iter().map(|ms| (ms - mean).powi(2)).sum::<f64>() / millis.len() as f64;
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            p99: millis[(millis.len() * 99 / 100).min(millis.len() - 1)],
+            max: millis.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+impl std::fmt::Display for FrameStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {:.3}ms  std dev {:.3}ms  p99 {:.3}ms  max {:.3}ms",
+            self.mean, self.std_dev, self.p99, self.max
+        )
+    }
+}
+
+fn measure(pooling: bool, frames: usize) -> Result<FrameStats, String> {
+    let mut app = headless_app(pooling);
+    app.world_mut()
+        .spawn(GameRun::new_single_level(corridor_01::get_config));
+    start_level(&mut app)?;
+    let mut millis = Vec::with_capacity(frames);
+    for frame in 0..WARMUP_FRAMES + frames {
+        // Restarting isn't timed, only frames where projectiles and explosions are coming and going
+        if level_over(app.world_mut()) {
+            start_level(&mut app)?;
+        }
+        let start = Instant::now();
+        app.update();
+        if frame >= WARMUP_FRAMES {
+            millis.push(start.elapsed().as_secs_f64() * 1000.);
+        }
+    }
+    Ok(FrameStats::new(millis))
+}
+
+fn main() -> std::process::ExitCode {
+    // Cargo passes --bench to benchmarks without the default harness
+    let mut frames = DEFAULT_FRAMES;
+    let mut raw = std::env::args().skip(1);
+    while let Some(arg) = raw.next() {
+        if arg == "--frames" {
+            match raw.next().and_then(|value| value.parse().ok()) {
+                Some(value) => frames = value,
+                None => {
+                    eprintln!("--frames needs a number");
+                    return std::process::ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    let results = [("despawning", false), ("pooled", true)].map(|(label, pooling)| {
+        let stats = measure(pooling, frames);
+        match &stats {
+            Ok(stats) => println!("{label:>10}: {stats}"),
+            Err(message) => println!("{label:>10}: ERROR {message}"),
+        }
+        stats
+    });
+    match results {
+        [Ok(despawning), Ok(pooled)] => {
+            println!(
+                "std dev with pooling is {:.0}% of despawning",
+                pooled.std_dev / despawning.std_dev * 100.
+            );
+            std::process::ExitCode::SUCCESS
+        }
+        _ => std::process::ExitCode::FAILURE,
+    }
+}
@@ -16,6 +16,11 @@ pub enum AppState {
     #[default]
     Loading,
     Title,
+    HighScores,
+    DailyChallenge,
+    CustomLevels,
+    Editor,
+    Settings,
     ResetGameRun,
     GameRun,
     // Always go to ResetGameplay which will auto transition to Gameplay while allowing LevelState::Loading to run again
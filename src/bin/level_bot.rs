@@ -0,0 +1,444 @@
+// Headless bot that searches for a way through every campaign and training level.
+//
+//     cargo run --release --bin level_bot -- [--rollouts N] [--seed N] [level name filter...]
+//
+// Each rollout restarts the level and plays a plan of movement and fire steps through the real
+// gameplay plugins at a fixed timestep. Plans that get further are mutated until the level is
+// cleared, then the search keeps going to find a faster clear.
+
+use std::time::Duration;
+
+use avian2d::prelude::*;
+use bevy::{
+    asset::AssetMetaCheck,
+    log::{Level, LogPlugin},
+    platform::collections::HashMap,
+    prelude::*,
+    render::{RenderPlugin, settings::WgpuSettings},
+    time::TimeUpdateStrategy,
+    window::ExitCondition,
+    winit::WinitPlugin,
+};
+use bevy_flair::prelude::FlairPlugin;
+use bevy_skein::SkeinPlugin;
+use maxx_obliterate::{
+    app_state::{AppState, AppStatePlugin},
+    gameplay::{
+        collisions::CollisionPlugin,
+        enemy::{Enemy, EnemyDestruction, EnemyPlugin},
+        energy::EnergyPlugin,
+        explosion::ExplosionPlugin,
+        game_run::{GameRun, GameRunPlugin},
+        high_scores::NewRecords,
+        level::{LevelConfig, LevelPlugin, LevelState, LevelStats},
+        player::{Player, PlayerOwnedWeapons, PlayerPlugin},
+        pool::PoolPlugin,
+        rating::RatingPlugin,
+        score::ScorePlugin,
+        stage::StagePlugin,
+        stage_bounds::StageBoundsPlugin,
+    },
+    levels::endless::LevelRng,
+    loading::{LoadingPlugin, SceneHandles},
+};
+
+// Matches the default fixed timestep so every update runs exactly one FixedUpdate
+const TICK: Duration = Duration::from_micros(15_625);
+const TICKS_PER_STEP: u32 = 16;
+const MAX_LEVEL_SECS: f32 = 30.;
+const MAX_SETUP_UPDATES: u32 = 600;
+const DEFAULT_ROLLOUTS: usize = 300;
+const DEATH_CELL_SIZE: f32 = 40.;
+const REPORTED_DEATH_CELLS: usize = 3;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Step {
+    direction: IVec2,
+    fire: bool,
+}
+
+impl Step {
+    fn random(rng: &mut LevelRng) -> Self {
+        let axis = |rng: &mut LevelRng| (rng.range(0., 3.) as i32).clamp(0, 2) - 1;
+        Self {
+            direction: ivec2(axis(rng), axis(rng)),
+            fire: rng.chance(0.7),
+        }
+    }
+    fn keys(&self) -> impl Iterator<Item = KeyCode> {
+        [
+            (self.direction.x < 0, KeyCode::ArrowLeft),
+            (self.direction.x > 0, KeyCode::ArrowRight),
+            (self.direction.y < 0, KeyCode::ArrowDown),
+            (self.direction.y > 0, KeyCode::ArrowUp),
+            (self.fire, KeyCode::Space),
+        ]
+        .into_iter()
+        .filter_map(|(pressed, key)| pressed.then_some(key))
+    }
+}
+
+type Plan = Vec<Step>;
+
+fn plan_len() -> usize {
+    (MAX_LEVEL_SECS / (TICK.as_secs_f32() * TICKS_PER_STEP as f32)) as usize
+}
+
+fn random_plan(rng: &mut LevelRng) -> Plan {
+    (0..plan_len()).map(|_| Step::random(rng)).collect()
+}
+
+// Rewrites a short run of steps, keeping everything before it so progress isn't lost
+fn mutate(plan: &Plan, rng: &mut LevelRng) -> Plan {
+    let mut plan = plan.clone();
+    let start = rng.range(0., plan.len() as f32) as usize;
+    let length = rng.range(1., 8.) as usize;
+    let step = Step::random(rng);
+    for index in start..(start + length).min(plan.len()) {
+        plan[index] = if rng.chance(0.5) {
+            step
+        } else {
+            Step::random(rng)
+        };
+    }
+    plan
+}
+
+#[derive(Clone, Debug)]
+enum Outcome {
+    Cleared { secs: f32 },
+    Died { secs: f32, position: Vec2 },
+    TimedOut,
+}
+
+#[derive(Clone, Debug)]
+struct Rollout {
+    outcome: Outcome,
+    required_destroyed: u32,
+}
+
+impl Rollout {
+    // Clears beat everything and faster clears beat slower ones, otherwise destroying more and
+    // surviving longer is progress
+    fn fitness(&self) -> f32 {
+        match self.outcome {
+            Outcome::Cleared { secs } => 10_000. - secs,
+            Outcome::Died { secs, .. } => self.required_destroyed as f32 * 10. + secs,
+            Outcome::TimedOut => self.required_destroyed as f32 * 10. + MAX_LEVEL_SECS,
+        }
+    }
+}
+
+struct LevelReport {
+    name: String,
+    fastest_clear: Option<f32>,
+    rollouts: usize,
+    deaths: Vec<Vec2>,
+}
+
+impl std::fmt::Display for LevelReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.fastest_clear {
+            Some(secs) => write!(f, "{}: CLEARED fastest {secs:.2} secs", self.name)?,
+            None => write!(f, "{}: NOT CLEARED", self.name)?,
+        }
+        write!(
+            f,
+            " ({} rollouts, {} deaths)",
+            self.rollouts,
+            self.deaths.len()
+        )?;
+        let mut cells = HashMap::<IVec2, usize>::new();
+        for position in &self.deaths {
+            *cells
+                .entry((*position / DEATH_CELL_SIZE).round().as_ivec2())
+                .or_default() += 1;
+        }
+        let mut cells = cells.into_iter().collect::<Vec<_>>();
+        cells.sort_by(|a, b| b.1.cmp(&a.1));
+        for (cell, count) in cells.into_iter().take(REPORTED_DEATH_CELLS) {
+            let position = cell.as_vec2() * DEATH_CELL_SIZE;
+            write!(
+                f,
+                "\n    {count} deaths near ({:.0}, {:.0})",
+                position.x, position.y
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(AssetPlugin {
+                meta_check: AssetMetaCheck::Never,
+                ..default()
+            })
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..default()
+            })
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..default()
+                }
+                .into(),
+                ..default()
+            })
+            .set(LogPlugin {
+                level: Level::ERROR,
+                ..default()
+            })
+            .disable::<WinitPlugin>(),
+    );
+    app.add_plugins((SkeinPlugin::default(), FlairPlugin));
+    app.add_plugins(PhysicsPlugins::default())
+        .insert_resource(Gravity(Vec2::ZERO));
+    // Only the plugins needed to play a level - the ones that save records are left out so the
+    // bot never touches the player's data
+    app.add_plugins((
+        AppStatePlugin,
+        CollisionPlugin,
+        EnemyPlugin,
+        EnergyPlugin,
+        ExplosionPlugin,
+        GameRunPlugin,
+        LevelPlugin,
+        LoadingPlugin,
+        PlayerPlugin,
+        PoolPlugin,
+        RatingPlugin,
+        ScorePlugin,
+        StagePlugin,
+        StageBoundsPlugin,
+    ))
+    .init_resource::<NewRecords>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(TICK));
+    app.finish();
+    app.cleanup();
+    app
+}
+
+fn update_until(app: &mut App, mut done: impl FnMut(&mut World) -> bool) -> Result<(), String> {
+    for _ in 0..MAX_SETUP_UPDATES {
+        if done(app.world_mut()) {
+            return Ok(());
+        }
+        app.update();
+    }
+    Err("Timed out waiting for the level to be ready".into())
+}
+
+fn set_keys(app: &mut App, step: &Step) {
+    let keys = step.keys().collect::<Vec<_>>();
+    let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    let released = keyboard
+        .get_pressed()
+        .filter(|key| !keys.contains(key))
+        .copied()
+        .collect::<Vec<_>>();
+    for key in released {
+        keyboard.release(key);
+    }
+    for key in keys {
+        keyboard.press(key);
+    }
+}
+
+fn play(app: &mut App, plan: &Plan) -> Result<Rollout, String> {
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::ResetGameplay);
+    update_until(app, |world| {
+        world
+            .get_resource::<State<LevelState>>()
+            .is_some_and(|state| state.get() == &LevelState::Ready)
+    })?;
+    // The player's weapons come from its scene, so wait for it before the clock starts
+    update_until(app, |world| {
+        world
+            .query_filtered::<(), (With<Player>, With<PlayerOwnedWeapons>)>()
+            .iter(world)
+            .next()
+            .is_some()
+    })?;
+    // Counted the same way as what's left at the end, so optional enemies don't count as progress
+    let required_total = required_enemy_count(app.world_mut());
+    app.world_mut()
+        .resource_mut::<NextState<LevelState>>()
+        .set(LevelState::Playing);
+    let mut last_position = Vec2::ZERO;
+    let ticks = plan.len() as u32 * TICKS_PER_STEP;
+    let mut outcome = Outcome::TimedOut;
+    for tick in 0..ticks {
+        set_keys(app, &plan[(tick / TICKS_PER_STEP) as usize]);
+        app.update();
+        let world = app.world_mut();
+        if let Some(transform) = world
+            .query_filtered::<&Transform, With<Player>>()
+            .iter(world)
+            .next()
+        {
+            last_position = transform.translation.truncate();
+        }
+        let Some(level_stats) = world.query::<&LevelStats>().iter(world).next() else {
+            continue;
+        };
+        let secs = level_stats.stopwatch.elapsed_secs();
+        match level_stats.success {
+            Some(true) => outcome = Outcome::Cleared { secs },
+            Some(false) => {
+                outcome = Outcome::Died {
+                    secs,
+                    position: last_position,
+                }
+            }
+            None => continue,
+        }
+        break;
+    }
+    set_keys(app, &Step::default());
+    let required_remaining = required_enemy_count(app.world_mut());
+    Ok(Rollout {
+        outcome,
+        required_destroyed: required_total.saturating_sub(required_remaining),
+    })
+}
+
+fn required_enemy_count(world: &mut World) -> u32 {
+    world
+        .query_filtered::<&EnemyDestruction, With<Enemy>>()
+        .iter(world)
+        .filter(|destruction| destruction == &&EnemyDestruction::Required)
+        .count() as u32
+}
+
+fn search_level(
+    app: &mut App,
+    get_config: fn(&SceneHandles) -> LevelConfig,
+    rollouts: usize,
+    rng: &mut LevelRng,
+) -> Result<LevelReport, String> {
+    let name = get_config(app.world().resource::<SceneHandles>()).name;
+    let game_run = app
+        .world_mut()
+        .spawn(GameRun::new_single_level(get_config))
+        .id();
+    let mut report = LevelReport {
+        name,
+        fastest_clear: None,
+        rollouts: 0,
+        deaths: Vec::new(),
+    };
+    // Standing still and firing is the first thing worth trying on any level
+    let mut best_plan = vec![
+        Step {
+            direction: IVec2::ZERO,
+            fire: true,
+        };
+        plan_len()
+    ];
+    let mut best_fitness = f32::MIN;
+    for index in 0..rollouts {
+        let plan = match index {
+            0 => best_plan.clone(),
+            _ if rng.chance(0.2) => random_plan(rng),
+            _ => mutate(&best_plan, rng),
+        };
+        let rollout = play(app, &plan)?;
+        report.rollouts += 1;
+        match rollout.outcome {
+            Outcome::Cleared { secs } => {
+                report.fastest_clear =
+                    Some(report.fastest_clear.map_or(secs, |best| best.min(secs)));
+            }
+            Outcome::Died { position, .. } => report.deaths.push(position),
+            Outcome::TimedOut => {}
+        }
+        if rollout.fitness() > best_fitness {
+            best_fitness = rollout.fitness();
+            best_plan = plan;
+        }
+    }
+    app.world_mut().despawn(game_run);
+    Ok(report)
+}
+
+struct Args {
+    rollouts: usize,
+    seed: u64,
+    filters: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        rollouts: DEFAULT_ROLLOUTS,
+        seed: 0,
+        filters: Vec::new(),
+    };
+    let mut raw = std::env::args().skip(1);
+    while let Some(arg) = raw.next() {
+        match arg.as_str() {
+            "--rollouts" => {
+                args.rollouts = raw
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or("--rollouts needs a number")?;
+            }
+            "--seed" => {
+                args.seed = raw
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or("--seed needs a number")?;
+            }
+            _ => args.filters.push(arg.to_lowercase()),
+        }
+    }
+    Ok(args)
+}
+
+fn main() -> std::process::ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            return std::process::ExitCode::FAILURE;
+        }
+    };
+    let mut app = headless_app();
+    let mut rng = LevelRng::new(args.seed);
+    let mut all_cleared = true;
+    for get_config in GameRun::game_levels()
+        .into_iter()
+        .chain(GameRun::training_levels())
+    {
+        let name = get_config(app.world().resource::<SceneHandles>()).name;
+        if !args.filters.is_empty()
+            && !args
+                .filters
+                .iter()
+                .any(|filter| name.to_lowercase().contains(filter))
+        {
+            continue;
+        }
+        match search_level(&mut app, get_config, args.rollouts, &mut rng) {
+            Ok(report) => {
+                all_cleared &= report.fastest_clear.is_some();
+                println!("{report}");
+            }
+            Err(message) => {
+                all_cleared = false;
+                println!("{name}: ERROR {message}");
+            }
+        }
+    }
+    if all_cleared {
+        std::process::ExitCode::SUCCESS
+    } else {
+        std::process::ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,182 @@
+use bevy::{prelude::*, scene::SceneInstanceReady};
+use bevy_flair::style::components::{NodeStyleSheet, NodeVars};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::{
+        enemy::{Enemy, EnemyClass},
+        level_theme::{MaterialTint, TintedMaterials, tint_scene_materials},
+        wave_markers::WaveMarker,
+    },
+    settings::Settings,
+};
+
+pub struct ColorPalettePlugin;
+
+impl Plugin for ColorPalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(tint_defender_waves)
+            .add_systems(Update, apply_palette_css_vars);
+    }
+}
+
+// Colours that carry meaning, chosen so each can be told apart with the matching colour vision
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorPalette {
+    #[default]
+    Standard,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+    HighContrast,
+}
+
+impl ColorPalette {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Standard => Self::Deuteranopia,
+            Self::Deuteranopia => Self::Protanopia,
+            Self::Protanopia => Self::Tritanopia,
+            Self::Tritanopia => Self::HighContrast,
+            Self::HighContrast => Self::Standard,
+        }
+    }
+    // Message id argument, see settings-color-palette in assets/locales
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Deuteranopia => "deuteranopia",
+            Self::Protanopia => "protanopia",
+            Self::Tritanopia => "tritanopia",
+            Self::HighContrast => "high-contrast",
+        }
+    }
+    // Headings, focused buttons and anything else that's good news
+    pub fn accent(&self) -> Color {
+        match self {
+            Self::Standard => Color::srgb(0., 0.5, 0.),
+            Self::Deuteranopia => Color::srgb_u8(0x00, 0x72, 0xb2),
+            Self::Protanopia => Color::srgb_u8(0x56, 0xb4, 0xe9),
+            Self::Tritanopia => Color::srgb_u8(0xcc, 0x79, 0xa7),
+            Self::HighContrast => Color::srgb(1., 1., 0.),
+        }
+    }
+    // New records and destroyed classes
+    pub fn highlight(&self) -> Color {
+        match self {
+            Self::Standard => Color::srgb(1., 0.65, 0.),
+            Self::Deuteranopia => Color::srgb_u8(0xe6, 0x9f, 0x00),
+            Self::Protanopia => Color::srgb_u8(0xf0, 0xe4, 0x42),
+            Self::Tritanopia => Color::srgb_u8(0xd5, 0x5e, 0x00),
+            Self::HighContrast => Color::srgb(0., 1., 1.),
+        }
+    }
+    // What's about to hurt, like the next chain wave and explosion reach
+    pub fn danger(&self) -> Color {
+        match self {
+            Self::Standard => Color::srgb(1., 0.3, 0.2),
+            Self::Deuteranopia => Color::srgb_u8(0xd5, 0x5e, 0x00),
+            Self::Protanopia => Color::srgb_u8(0xe6, 0x9f, 0x00),
+            Self::Tritanopia => Color::srgb_u8(0xe4, 0x00, 0x2b),
+            Self::HighContrast => Color::srgb(1., 0., 1.),
+        }
+    }
+    // What will hurt later on
+    pub fn warning(&self) -> Color {
+        match self {
+            Self::Standard => Color::srgb(1., 0.8, 0.3),
+            Self::Deuteranopia => Color::srgb_u8(0xf0, 0xe4, 0x42),
+            Self::Protanopia => Color::srgb_u8(0xff, 0xff, 0xff),
+            Self::Tritanopia => Color::srgb_u8(0x00, 0x9e, 0x73),
+            Self::HighContrast => Color::WHITE,
+        }
+    }
+    // One per defender wave, in wave order
+    pub fn wave(&self, class: EnemyClass) -> Option<Color> {
+        let colors = match self {
+            Self::Standard => [
+                Color::srgb(1., 0.4, 0.4),
+                Color::srgb(0.4, 0.7, 1.),
+                Color::srgb(1., 0.9, 0.4),
+            ],
+            // Both confuse the same reds and greens, so one set of wave colours covers them
+            Self::Deuteranopia | Self::Protanopia => [
+                Color::srgb_u8(0xe6, 0x9f, 0x00),
+                Color::srgb_u8(0x56, 0xb4, 0xe9),
+                Color::srgb_u8(0xf0, 0xe4, 0x42),
+            ],
+            Self::Tritanopia => [
+                Color::srgb_u8(0xd5, 0x5e, 0x00),
+                Color::srgb_u8(0x00, 0x9e, 0x73),
+                Color::srgb_u8(0xcc, 0x79, 0xa7),
+            ],
+            Self::HighContrast => [
+                Color::srgb(1., 0., 1.),
+                Color::srgb(0., 1., 1.),
+                Color::srgb(1., 1., 0.),
+            ],
+        };
+        match class {
+            EnemyClass::DefenderOne => Some(colors[0]),
+            EnemyClass::DefenderTwo => Some(colors[1]),
+            EnemyClass::DefenderThree => Some(colors[2]),
+            _ => None,
+        }
+    }
+    fn set_css_vars(&self, vars: &mut NodeVars) {
+        for (name, color) in [
+            ("--accent-color", self.accent()),
+            ("--highlight-color", self.highlight()),
+        ] {
+            // Hex colours always parse
+            let _ = vars.set_var(name, &color.to_srgba().to_hex());
+        }
+    }
+}
+
+fn apply_palette_css_vars(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    root_q: Query<(Entity, Ref<NodeStyleSheet>), Without<ChildOf>>,
+) {
+    for (entity, style_sheet) in &root_q {
+        if settings.is_changed() || style_sheet.is_added() {
+            let palette = settings.color_palette;
+            commands
+                .entity(entity)
+                .entry::<NodeVars>()
+                .or_default()
+                .and_modify(move |mut vars| palette.set_css_vars(&mut vars));
+        }
+    }
+}
+
+// The models only differ by a small icon, so other palettes colour the whole defender, icon and
+// all, in the wave's colour
+fn tint_defender_waves(
+    trigger: Trigger<SceneInstanceReady>,
+    settings: Res<Settings>,
+    class_q: Query<&EnemyClass, With<Enemy>>,
+    children_q: Query<&Children>,
+    material_q: Query<&mut MeshMaterial3d<StandardMaterial>, Without<WaveMarker>>,
+    materials: ResMut<Assets<StandardMaterial>>,
+    tinted_materials: ResMut<TintedMaterials>,
+) {
+    if settings.color_palette == ColorPalette::Standard {
+        return;
+    }
+    let Ok(&class) = class_q.get(trigger.target()) else {
+        return;
+    };
+    let Some(tint) = settings.color_palette.wave(class) else {
+        return;
+    };
+    tint_scene_materials(
+        trigger.target(),
+        MaterialTint::replace(tint),
+        &children_q,
+        material_q,
+        materials,
+        tinted_materials,
+    );
+}
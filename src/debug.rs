@@ -1,7 +1,10 @@
 use avian2d::prelude::PhysicsDebugPlugin;
 use bevy::{dev_tools::states::log_transitions, prelude::*};
 
-use crate::{app_state::AppState, gameplay::level::LevelState};
+use crate::{
+    app_state::AppState, gameplay::level::LevelState,
+    levels::validation::validate_registered_levels, loading::SceneHandles,
+};
 
 const DEBUG_PHYSICS: bool = false;
 const DEBUG_SCREEN_STATE: bool = false;
@@ -12,6 +15,7 @@ pub struct DebugPlugin;
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(ShowAxis(false))
+            .add_systems(Startup, warn_about_level_problems)
             .add_systems(Update, draw_axis);
         if DEBUG_PHYSICS {
             app.add_plugins(PhysicsDebugPlugin::default());
@@ -28,6 +32,18 @@ impl Plugin for DebugPlugin {
 #[derive(Resource)]
 struct ShowAxis(bool);
 
+fn warn_about_level_problems(scenes: Res<SceneHandles>) {
+    for (name, problems) in validate_registered_levels(&scenes) {
+        for problem in problems {
+            if problem.is_error() {
+                warn!("Level \"{name}\": {problem}");
+            } else {
+                info!("Level \"{name}\": {problem}");
+            }
+        }
+    }
+}
+
 fn draw_axis(mut gizmos: Gizmos, show_axis: Res<ShowAxis>) {
     if show_axis.0 {
         gizmos.axes_2d(Transform::default(), 100.);
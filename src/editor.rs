@@ -0,0 +1,770 @@
+use bevy::{
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+        mouse::MouseWheel,
+    },
+    prelude::*,
+};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        enemy::EnemyClass,
+        game_run::GameRun,
+        level::LevelConfig,
+        player::PLAYER_SIZE,
+        stage::{STAGE_HEIGHT, STAGE_WIDTH, StageView},
+    },
+    levels::{
+        gltf_level::ImportedLevels,
+        level_file::{LevelFile, LevelPiece},
+        validation::{LevelProblem, validate},
+    },
+    loading::SceneHandles,
+    localization::{Localization, Localized},
+};
+
+const GRID_SIZE: f32 = 10.;
+// Lines are drawn less often than pieces snap so the grid doesn't drown out the level
+const GRID_LINE_SPACING: f32 = GRID_SIZE * 4.;
+const MIN_PIECE_SCALE: f32 = GRID_SIZE;
+// Above every piece, including walls
+const GIZMO_Z: f32 = 50.;
+const START_MARKER_Z: f32 = 20.;
+// Stages grow and shrink half a screen at a time and never get shorter than one
+const STAGE_HEIGHT_STEP: f32 = STAGE_HEIGHT / 2.;
+const PAGE_SCROLL: f32 = STAGE_HEIGHT / 2.;
+const WHEEL_SCROLL: f32 = GRID_LINE_SPACING;
+
+const PLACEABLE: [(KeyCode, EnemyClass); 7] = [
+    (KeyCode::Digit1, EnemyClass::Base),
+    (KeyCode::Digit2, EnemyClass::DefenderOne),
+    (KeyCode::Digit3, EnemyClass::DefenderTwo),
+    (KeyCode::Digit4, EnemyClass::DefenderThree),
+    (KeyCode::Digit5, EnemyClass::Shadow),
+    (KeyCode::Digit6, EnemyClass::Land),
+    (KeyCode::Digit7, EnemyClass::Wall),
+];
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorLevel>()
+            .init_resource::<EditorTools>()
+            .init_resource::<EditorCursor>()
+            .init_resource::<EditorProblems>()
+            .add_systems(OnEnter(AppState::Editor), spawn_editor)
+            .add_systems(OnExit(AppState::Editor), reset_stage_view)
+            .add_systems(
+                Update,
+                (
+                    update_cursor,
+                    handle_shortcuts,
+                    edit_text,
+                    handle_mouse,
+                    sync_stage_view.run_if(resource_changed::<EditorLevel>),
+                    sync_editor_pieces.run_if(resource_changed::<EditorLevel>),
+                    validate_editor_level,
+                    update_editor_text.run_if(
+                        resource_changed::<EditorLevel>
+                            .or(resource_changed::<EditorTools>)
+                            .or(resource_changed::<EditorProblems>)
+                            .or(resource_changed::<Localization>),
+                    ),
+                    draw_editor_gizmos,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Editor)),
+            );
+    }
+}
+
+// The level being edited, kept between visits so play-testing doesn't lose work
+#[derive(Resource, Debug, Default)]
+pub struct EditorLevel(pub LevelFile);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TextField {
+    Name,
+    Notes,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Drag {
+    // Offsets keep the grabbed point under the cursor
+    Piece { index: usize, offset: Vec2 },
+    Start { offset: Vec2 },
+}
+
+#[derive(Resource, Debug)]
+struct EditorTools {
+    class: EnemyClass,
+    selected: Option<usize>,
+    snap: bool,
+    drag: Option<Drag>,
+    typing: Option<TextField>,
+    source_index: usize,
+    status: Localized,
+}
+
+impl Default for EditorTools {
+    fn default() -> Self {
+        Self {
+            class: EnemyClass::Base,
+            selected: None,
+            snap: true,
+            drag: None,
+            typing: None,
+            source_index: 0,
+            status: Localized::new("editor-welcome"),
+        }
+    }
+}
+
+impl EditorTools {
+    fn snap(&self, position: Vec2) -> Vec2 {
+        if self.snap {
+            (position / GRID_SIZE).round() * GRID_SIZE
+        } else {
+            position
+        }
+    }
+    fn step(&self) -> f32 {
+        if self.snap { GRID_SIZE } else { 1. }
+    }
+}
+
+#[derive(Resource, Debug, Default, PartialEq)]
+struct EditorCursor(Option<Vec2>);
+
+// Found when the level last settled, so dragging doesn't check the whole level every frame
+#[derive(Resource, Debug, Default)]
+struct EditorProblems {
+    problems: Vec<LevelProblem>,
+    stale: bool,
+}
+
+#[derive(Component, Debug)]
+struct EditorPiece {
+    index: usize,
+    class: EnemyClass,
+}
+
+#[derive(Component, Debug)]
+struct EditorStartMarker;
+
+#[derive(Component, Debug)]
+struct EditorBackground;
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+enum EditorText {
+    Name,
+    Notes,
+    Status,
+}
+
+// Everything that can be opened, in the order F3 steps through them
+enum LevelSource {
+    Blank,
+    BuiltIn(fn(&SceneHandles) -> LevelConfig),
+    Imported(LevelFile),
+    Saved(String),
+}
+
+impl LevelSource {
+    fn all(imported_levels: &ImportedLevels) -> Vec<Self> {
+        std::iter::once(LevelSource::Blank)
+            .chain(
+                GameRun::game_levels()
+                    .into_iter()
+                    .chain(GameRun::training_levels())
+                    .map(LevelSource::BuiltIn),
+            )
+            .chain(
+                imported_levels
+                    .levels()
+                    .map(|(_, level_file)| LevelSource::Imported(level_file.clone())),
+            )
+            .chain(LevelFile::saved_keys().into_iter().map(LevelSource::Saved))
+            .collect()
+    }
+    fn open(&self, scenes: &SceneHandles) -> LevelFile {
+        match self {
+            LevelSource::Blank => LevelFile::default(),
+            LevelSource::BuiltIn(get_config) => LevelFile::from_config(&get_config(scenes)),
+            LevelSource::Imported(level_file) => level_file.clone(),
+            LevelSource::Saved(key) => LevelFile::load(key),
+        }
+    }
+}
+
+// The smallest piece under the cursor so defenders sitting on land can still be picked
+fn piece_at(level_file: &LevelFile, position: Vec2) -> Option<usize> {
+    level_file
+        .pieces
+        .iter()
+        .enumerate()
+        .filter(|(_, piece)| piece.rect().contains(position))
+        .min_by(|(_, a), (_, b)| {
+            a.size()
+                .element_product()
+                .total_cmp(&b.size().element_product())
+        })
+        .map(|(index, _)| index)
+}
+
+fn remove_piece(level: &mut EditorLevel, tools: &mut EditorTools, index: usize) {
+    level.0.pieces.remove(index);
+    tools.drag = None;
+    tools.selected = match tools.selected {
+        Some(selected) if selected == index => None,
+        Some(selected) if selected > index => Some(selected - 1),
+        selected => selected,
+    };
+}
+
+fn resize_piece(piece: &mut LevelPiece, delta: Vec2) -> Result<(), Localized> {
+    if !piece.class.is_scalable() {
+        return Err(Localized::new("editor-fixed-size"));
+    }
+    piece.scale = (piece.scale() + delta)
+        .max(Vec2::splat(MIN_PIECE_SCALE))
+        .into();
+    Ok(())
+}
+
+fn spawn_editor(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    mut level: ResMut<EditorLevel>,
+    mut tools: ResMut<EditorTools>,
+) {
+    // Pieces are respawned from the level whenever it changes
+    level.set_changed();
+    tools.drag = None;
+    tools.typing = None;
+    commands.spawn((
+        StateScoped(AppState::Editor),
+        EditorBackground,
+        scenes.background(),
+    ));
+    commands.spawn((
+        StateScoped(AppState::Editor),
+        Name::new("Editor Start Marker"),
+        EditorStartMarker,
+        scenes.player(),
+        Transform::from_translation(level.0.start_position().extend(START_MARKER_Z)),
+    ));
+    commands.spawn((
+        StateScoped(AppState::Editor),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        ClassList::new_with_classes(["level-info-panel"]),
+        Children::spawn_one((
+            Node::default(),
+            ClassList::new_with_classes(["level-info-panel-content"]),
+            children![
+                (
+                    Text::default(),
+                    EditorText::Notes,
+                    ClassList::new_with_classes(["text"])
+                ),
+                (
+                    Text::default(),
+                    EditorText::Name,
+                    ClassList::new_with_classes(["heading"])
+                ),
+            ],
+        )),
+    ));
+    commands.spawn((
+        StateScoped(AppState::Editor),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        ClassList::new_with_classes(["level-stats-panel"]),
+        children![
+            (
+                Text::default(),
+                EditorText::Status,
+                ClassList::new_with_classes(["editor-status"])
+            ),
+            (
+                Text::default(),
+                Localized::new("editor-help"),
+                ClassList::new_with_classes(["editor-help"])
+            ),
+        ],
+    ));
+}
+
+fn update_cursor(
+    mut cursor: ResMut<EditorCursor>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform)>,
+) {
+    let (camera, camera_transform) = *camera;
+    // The camera looks straight down so the ray starts right above the stage position
+    let position = window
+        .cursor_position()
+        .and_then(|position| camera.viewport_to_world(camera_transform, position).ok())
+        .map(|ray| ray.origin.truncate());
+    cursor.set_if_neq(EditorCursor(position));
+}
+
+fn handle_shortcuts(
+    mut commands: Commands,
+    scenes: Res<SceneHandles>,
+    imported_levels: Res<ImportedLevels>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    cursor: Res<EditorCursor>,
+    mut level: ResMut<EditorLevel>,
+    mut tools: ResMut<EditorTools>,
+    mut view: ResMut<StageView>,
+    mut next_state: ResMut<NextState<AppState>>,
+    game_run_q: Query<Entity, With<GameRun>>,
+) {
+    if tools.typing.is_some() {
+        return;
+    }
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    for (key, class) in PLACEABLE {
+        if keyboard.just_pressed(key) {
+            tools.class = class;
+        }
+    }
+    if let Some(index) = tools.selected {
+        let direction = [
+            (KeyCode::ArrowLeft, Vec2::NEG_X),
+            (KeyCode::ArrowRight, Vec2::X),
+            (KeyCode::ArrowUp, Vec2::Y),
+            (KeyCode::ArrowDown, Vec2::NEG_Y),
+        ]
+        .into_iter()
+        .filter(|(key, _)| keyboard.just_pressed(*key))
+        .map(|(_, direction)| direction)
+        .sum::<Vec2>();
+        if direction != Vec2::ZERO {
+            let delta = direction * tools.step();
+            let piece = &mut level.0.pieces[index];
+            if shift {
+                if let Err(message) = resize_piece(piece, delta) {
+                    tools.status = message;
+                }
+            } else {
+                piece.position = (piece.position() + delta).into();
+            }
+        }
+        if keyboard.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+            remove_piece(&mut level, &mut tools, index);
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Tab) && !level.0.pieces.is_empty() {
+        let count = level.0.pieces.len();
+        tools.selected = Some(match tools.selected {
+            Some(index) if shift => (index + count - 1) % count,
+            Some(index) => (index + 1) % count,
+            None => 0,
+        });
+    }
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        tools.snap = !tools.snap;
+    }
+    if keyboard.just_pressed(KeyCode::PageUp) {
+        view.scroll_by(PAGE_SCROLL);
+    }
+    if keyboard.just_pressed(KeyCode::PageDown) {
+        view.scroll_by(-PAGE_SCROLL);
+    }
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        let step = if shift {
+            -STAGE_HEIGHT_STEP
+        } else {
+            STAGE_HEIGHT_STEP
+        };
+        level.0.stage.size.1 = (level.0.stage.size.1 + step).max(STAGE_HEIGHT);
+        // Stays where it was rather than jumping back to the start like opening a level does
+        *view = StageView {
+            stage: level.0.stage,
+            center: view.center,
+        };
+        view.scroll_by(0.);
+        tools.status = Localized::new("editor-stage-height")
+            .with_arg("height", format!("{:.0}", level.0.stage.size.1));
+    }
+    if let Some(position) = cursor.0.filter(|_| keyboard.just_pressed(KeyCode::KeyS)) {
+        level.0.start_position = tools.snap(position).into();
+    }
+    if keyboard.just_pressed(KeyCode::KeyN) {
+        tools.typing = Some(TextField::Name);
+    }
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        tools.typing = Some(TextField::Notes);
+    }
+    if keyboard.just_pressed(KeyCode::F2) {
+        let key = level.0.save();
+        tools.status = Localized::new("editor-saved").with_arg("key", key);
+    }
+    if keyboard.just_pressed(KeyCode::F3) {
+        let sources = LevelSource::all(&imported_levels);
+        tools.source_index = if shift {
+            (tools.source_index + sources.len() - 1) % sources.len()
+        } else {
+            (tools.source_index + 1) % sources.len()
+        };
+        level.0 = sources[tools.source_index].open(&scenes);
+        tools.selected = None;
+        tools.status = Localized::new("editor-opened").with_arg("name", level.0.name.as_str());
+    }
+    if keyboard.just_pressed(KeyCode::F4) {
+        level.0 = LevelFile::default();
+        tools.selected = None;
+        tools.source_index = 0;
+        tools.status = Localized::new("editor-new-level");
+    }
+    if keyboard.just_pressed(KeyCode::KeyP) {
+        // Play-testing goes through the same run and level pipeline as every other mode
+        for game_run in &game_run_q {
+            commands.entity(game_run).despawn();
+        }
+        commands.spawn(GameRun::new_playtest(level.0.clone()));
+        next_state.set(AppState::ResetGameplay);
+    }
+    if keyboard.just_pressed(KeyCode::Escape) {
+        next_state.set(AppState::Title);
+    }
+}
+
+fn edit_text(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut level: ResMut<EditorLevel>,
+    mut tools: ResMut<EditorTools>,
+    mut was_typing: Local<bool>,
+) {
+    let Some(field) = tools.typing else {
+        *was_typing = false;
+        keyboard_events.clear();
+        return;
+    };
+    // The key that started typing arrives in the same frame and isn't part of the text
+    if !*was_typing {
+        *was_typing = true;
+        keyboard_events.clear();
+        return;
+    }
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let text = match field {
+            TextField::Name => &mut level.0.name,
+            TextField::Notes => &mut level.0.notes,
+        };
+        match &event.logical_key {
+            Key::Character(characters) => {
+                text.extend(characters.chars().filter(|c| !c.is_control()));
+            }
+            Key::Space => text.push(' '),
+            Key::Backspace => {
+                text.pop();
+            }
+            Key::Enter if field == TextField::Notes => text.push('\n'),
+            Key::Enter | Key::Escape => {
+                tools.typing = None;
+                break;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn handle_mouse(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut wheel_events: EventReader<MouseWheel>,
+    cursor: Res<EditorCursor>,
+    mut level: ResMut<EditorLevel>,
+    mut tools: ResMut<EditorTools>,
+    mut view: ResMut<StageView>,
+) {
+    let scroll = wheel_events.read().map(|event| event.y).sum::<f32>();
+    if tools.selected.is_none() && scroll != 0. {
+        view.scroll_by(scroll.signum() * WHEEL_SCROLL);
+    }
+    let Some(cursor) = cursor.0 else {
+        return;
+    };
+    let on_stage = level.0.stage.rect().contains(cursor);
+    if mouse.just_pressed(MouseButton::Left) && on_stage {
+        tools.typing = None;
+        let start = level.0.start_position();
+        if Rect::from_center_size(start, PLAYER_SIZE).contains(cursor) {
+            tools.drag = Some(Drag::Start {
+                offset: start - cursor,
+            });
+        } else if let Some(index) = piece_at(&level.0, cursor) {
+            tools.selected = Some(index);
+            tools.drag = Some(Drag::Piece {
+                index,
+                offset: level.0.pieces[index].position() - cursor,
+            });
+        } else {
+            let position = tools.snap(cursor);
+            level.0.pieces.push(LevelPiece::new(tools.class, position));
+            let index = level.0.pieces.len() - 1;
+            tools.selected = Some(index);
+            tools.drag = Some(Drag::Piece {
+                index,
+                offset: position - cursor,
+            });
+        }
+    }
+    if mouse.pressed(MouseButton::Left) {
+        match tools.drag {
+            Some(Drag::Piece { index, offset }) => {
+                let position = tools.snap(cursor + offset);
+                if level.0.pieces[index].position() != position {
+                    level.0.pieces[index].position = position.into();
+                }
+            }
+            Some(Drag::Start { offset }) => {
+                let position = tools.snap(cursor + offset);
+                if level.0.start_position() != position {
+                    level.0.start_position = position.into();
+                }
+            }
+            None => {}
+        }
+    }
+    if mouse.just_released(MouseButton::Left) && tools.drag.is_some() {
+        tools.drag = None;
+    }
+    let right_clicked = mouse.just_pressed(MouseButton::Right) && on_stage;
+    if let Some(index) = piece_at(&level.0, cursor).filter(|_| right_clicked) {
+        remove_piece(&mut level, &mut tools, index);
+    }
+    if let Some(index) = tools.selected.filter(|_| scroll != 0.) {
+        let axis = if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            Vec2::X
+        } else {
+            Vec2::Y
+        };
+        let delta = axis * scroll.signum() * tools.step();
+        if let Err(message) = resize_piece(&mut level.0.pieces[index], delta) {
+            tools.status = message;
+        }
+    }
+}
+
+fn sync_editor_pieces(
+    mut commands: Commands,
+    scenes: Res<SceneHandles>,
+    level: Res<EditorLevel>,
+    mut pieces_q: Query<(Entity, &EditorPiece, &mut Transform), Without<EditorStartMarker>>,
+    mut start_q: Query<&mut Transform, With<EditorStartMarker>>,
+) {
+    let pieces = &level.0.pieces;
+    // Moving and resizing only touch transforms so scenes don't flicker while dragging
+    let same_pieces = pieces_q.iter().count() == pieces.len()
+        && pieces_q.iter().all(|(_, editor_piece, _)| {
+            pieces
+                .get(editor_piece.index)
+                .is_some_and(|piece| piece.class == editor_piece.class)
+        });
+    if same_pieces {
+        for (_, editor_piece, mut transform) in &mut pieces_q {
+            let piece = &pieces[editor_piece.index];
+            // Each class keeps its own depth
+            transform.translation = piece.position().extend(transform.translation.z);
+            transform.scale = piece.scale().extend(1.);
+        }
+    } else {
+        for (entity, _, _) in &pieces_q {
+            commands.entity(entity).despawn();
+        }
+        let enemies = level.0.to_config(&scenes).enemies;
+        for (index, enemy) in enemies.iter().enumerate() {
+            commands.spawn((
+                StateScoped(AppState::Editor),
+                Name::new("Editor Piece"),
+                EditorPiece {
+                    index,
+                    class: enemy.class(),
+                },
+                enemy.scene(),
+                enemy.transform(),
+            ));
+        }
+    }
+    for mut transform in &mut start_q {
+        transform.translation = level.0.start_position().extend(START_MARKER_Z);
+    }
+}
+
+// Opening a level shows it from where the player starts
+fn sync_stage_view(
+    level: Res<EditorLevel>,
+    mut view: ResMut<StageView>,
+    mut background_q: Query<&mut Transform, With<EditorBackground>>,
+) {
+    if view.stage != level.0.stage {
+        *view = StageView::new(level.0.stage, level.0.start_position());
+    }
+    // The background is modelled for a single screen
+    let scale = level.0.stage.size() / vec2(STAGE_WIDTH, STAGE_HEIGHT);
+    for mut transform in &mut background_q {
+        transform.scale = scale.extend(1.);
+    }
+}
+
+fn reset_stage_view(mut commands: Commands) {
+    commands.insert_resource(StageView::default());
+}
+
+// Waits for a drag to finish so only the level the piece is dropped into gets checked
+fn validate_editor_level(
+    scenes: Res<SceneHandles>,
+    level: Res<EditorLevel>,
+    tools: Res<EditorTools>,
+    mut problems: ResMut<EditorProblems>,
+) {
+    if level.is_changed() {
+        problems.stale = true;
+    }
+    if !problems.stale || tools.drag.is_some() {
+        return;
+    }
+    *problems = EditorProblems {
+        problems: validate(&level.0.to_config(&scenes)),
+        stale: false,
+    };
+}
+
+fn update_editor_text(
+    level: Res<EditorLevel>,
+    tools: Res<EditorTools>,
+    problems: Res<EditorProblems>,
+    localization: Res<Localization>,
+    text_q: Query<(&mut Text, &EditorText)>,
+) {
+    let cursor_for = |field: TextField| {
+        if tools.typing == Some(field) { "_" } else { "" }
+    };
+    let class_name = |class: EnemyClass| localization.text(class.message_id());
+    let rounded = |value: f32| format!("{value:.0}");
+    let selected = match tools.selected.and_then(|index| level.0.pieces.get(index)) {
+        Some(piece) if piece.class.is_scalable() => Localized::new("editor-selected-sized")
+            .with_arg("class", class_name(piece.class))
+            .with_arg("x", rounded(piece.position.0))
+            .with_arg("y", rounded(piece.position.1))
+            .with_arg("width", rounded(piece.scale.0))
+            .with_arg("height", rounded(piece.scale.1)),
+        Some(piece) => Localized::new("editor-selected")
+            .with_arg("class", class_name(piece.class))
+            .with_arg("x", rounded(piece.position.0))
+            .with_arg("y", rounded(piece.position.1)),
+        None => Localized::new("editor-selected-nothing"),
+    };
+    let problems = problems
+        .problems
+        .iter()
+        .map(|problem| {
+            let kind = if problem.is_error() { "error" } else { "note" };
+            Localized::new("editor-problem")
+                .with_arg("kind", kind)
+                .with_arg("problem", localization.format(&problem.message()))
+        })
+        .collect::<Vec<_>>();
+    let mut lines = vec![
+        Localized::new("editor-placing").with_arg("class", class_name(tools.class)),
+        Localized::new("editor-snap").with_arg("value", if tools.snap { "on" } else { "off" }),
+        Localized::new("editor-start")
+            .with_arg("x", rounded(level.0.start_position.0))
+            .with_arg("y", rounded(level.0.start_position.1)),
+        selected,
+        tools.status.clone(),
+    ];
+    if problems.is_empty() {
+        lines.push(Localized::new("editor-no-problems"));
+    } else {
+        lines.extend(problems);
+    }
+    let status = lines
+        .iter()
+        .map(|line| localization.format(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    for (mut text, editor_text) in text_q {
+        text.0 = match editor_text {
+            EditorText::Name => format!("{}{}", level.0.name, cursor_for(TextField::Name)),
+            EditorText::Notes if level.0.notes.is_empty() && tools.typing.is_none() => {
+                localization.text("editor-notes-hint")
+            }
+            EditorText::Notes => format!("{}{}", level.0.notes, cursor_for(TextField::Notes)),
+            EditorText::Status => status.clone(),
+        };
+    }
+}
+
+fn draw_editor_gizmos(
+    mut gizmos: Gizmos,
+    level: Res<EditorLevel>,
+    tools: Res<EditorTools>,
+    cursor: Res<EditorCursor>,
+) {
+    let at = |position: Vec2| Isometry3d::from_translation(position.extend(GIZMO_Z));
+    let stage = level.0.stage.rect();
+    if tools.snap {
+        gizmos.grid(
+            at(stage.center()),
+            (stage.size() / GRID_LINE_SPACING).as_uvec2(),
+            Vec2::splat(GRID_LINE_SPACING),
+            Color::srgba(1., 1., 1., 0.08),
+        );
+    }
+    gizmos.rect(
+        at(stage.center()),
+        stage.size(),
+        Color::srgba(1., 1., 1., 0.4),
+    );
+    gizmos.rect(
+        at(level.0.start_position()),
+        PLAYER_SIZE,
+        Color::srgb(0., 0.8, 0.),
+    );
+    if let Some(piece) = tools.selected.and_then(|index| level.0.pieces.get(index)) {
+        gizmos.rect(
+            at(piece.position()),
+            piece.size() + 4.,
+            Color::srgb(1., 0.8, 0.),
+        );
+    }
+    // Preview what a click would do
+    let Some(cursor) = cursor
+        .0
+        .filter(|cursor| tools.drag.is_none() && stage.contains(*cursor))
+    else {
+        return;
+    };
+    match piece_at(&level.0, cursor) {
+        Some(index) => {
+            let piece = &level.0.pieces[index];
+            gizmos.rect(
+                at(piece.position()),
+                piece.size() + 2.,
+                Color::srgba(1., 1., 1., 0.6),
+            );
+        }
+        None => {
+            let piece = LevelPiece::new(tools.class, tools.snap(cursor));
+            gizmos.rect(
+                at(piece.position()),
+                piece.size(),
+                Color::srgba(1., 1., 1., 0.3),
+            );
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use bevy::prelude::*;
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        enemy::{EnemyClass, EnemyDestroyedEvent, EnemyDestructionSource},
+        explosion::ExplosionChainEvent,
+        player::PlayerDestroyedEvent,
+    },
+    settings::Settings,
+};
+
+const BASE_DESTROYED_TRAUMA: f32 = 0.5;
+const CHAIN_WAVE_TRAUMA: f32 = 0.3;
+const PLAYER_DESTROYED_TRAUMA: f32 = 0.9;
+// Trauma lost per second
+const TRAUMA_DECAY: f32 = 1.2;
+const MAX_SHAKE_OFFSET: f32 = 14.;
+const MAX_SHAKE_ROLL: f32 = 0.04;
+// Real seconds the game runs slowed down for after the player's shot destroys a Base
+const HIT_STOP_SECS: f32 = 0.08;
+const HIT_STOP_SPEED: f32 = 0.05;
+const FLASH_SECS: f32 = 0.15;
+const FLASH_ALPHA: f32 = 0.5;
+
+pub struct CameraEffectsPlugin;
+
+impl Plugin for CameraEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HitStop>()
+            .add_observer(shake_on_enemy_destroyed)
+            .add_observer(shake_on_chain_wave)
+            .add_observer(shake_on_player_destroyed)
+            .add_systems(Update, (shake_camera, update_hit_stop, fade_screen_flash))
+            .add_systems(OnExit(AppState::Gameplay), reset_camera_effects);
+    }
+}
+
+// Trauma builds up from hits and falls away over time, the shake grows with its square
+#[derive(Component, Debug)]
+pub struct CameraShake {
+    trauma: f32,
+    home: Transform,
+}
+
+impl CameraShake {
+    pub fn new(home: Transform) -> Self {
+        Self { trauma: 0., home }
+    }
+    // Where the camera sits when it isn't shaking, which scrolling stages move
+    pub fn set_home_position(&mut self, position: Vec2) {
+        self.home.translation.x = position.x;
+        self.home.translation.y = position.y;
+    }
+    fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).min(1.);
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+struct HitStop {
+    remaining: f32,
+}
+
+#[derive(Component, Debug)]
+struct ScreenFlash(Timer);
+
+fn screen_flash() -> impl Bundle {
+    (
+        Name::new("Screen Flash"),
+        StateScoped(AppState::Gameplay),
+        ScreenFlash(Timer::from_seconds(FLASH_SECS, TimerMode::Once)),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            ..default()
+        },
+        BackgroundColor(Color::WHITE.with_alpha(FLASH_ALPHA)),
+        GlobalZIndex(i32::MAX),
+        Pickable::IGNORE,
+    )
+}
+
+fn shake_on_enemy_destroyed(
+    trigger: Trigger<EnemyDestroyedEvent>,
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+    mut camera_q: Query<&mut CameraShake>,
+) {
+    let event = trigger.event();
+    if event.class != EnemyClass::Base || settings.reduced_motion {
+        return;
+    }
+    for mut shake in &mut camera_q {
+        shake.add_trauma(BASE_DESTROYED_TRAUMA);
+    }
+    if event.destruction_source == EnemyDestructionSource::Player {
+        hit_stop.remaining = HIT_STOP_SECS;
+        time.set_relative_speed(HIT_STOP_SPEED);
+        if settings.shows_screen_flash() {
+            commands.spawn(screen_flash());
+        }
+    }
+}
+
+fn shake_on_chain_wave(
+    _trigger: Trigger<ExplosionChainEvent>,
+    settings: Res<Settings>,
+    mut camera_q: Query<&mut CameraShake>,
+) {
+    if settings.reduced_motion {
+        return;
+    }
+    for mut shake in &mut camera_q {
+        shake.add_trauma(CHAIN_WAVE_TRAUMA);
+    }
+}
+
+fn shake_on_player_destroyed(
+    _trigger: Trigger<PlayerDestroyedEvent>,
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut camera_q: Query<&mut CameraShake>,
+) {
+    if settings.reduced_motion {
+        return;
+    }
+    for mut shake in &mut camera_q {
+        shake.add_trauma(PLAYER_DESTROYED_TRAUMA);
+    }
+    if settings.shows_screen_flash() {
+        commands.spawn(screen_flash());
+    }
+}
+
+// Uses real time so the shake keeps going through hit-stop
+fn shake_camera(
+    time: Res<Time<Real>>,
+    settings: Res<Settings>,
+    mut camera_q: Query<(&mut CameraShake, &mut Transform)>,
+) {
+    for (mut shake, mut transform) in &mut camera_q {
+        if settings.reduced_motion {
+            shake.trauma = 0.;
+        }
+        shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_secs()).max(0.);
+        let amount = shake.trauma * shake.trauma;
+        // Waves at unrelated frequencies are smooth but don't visibly repeat
+        let t = time.elapsed_secs();
+        let offset = vec2(
+            (t * 37.).sin() + (t * 53.).sin() * 0.5,
+            (t * 41.).sin() + (t * 59.).cos() * 0.5,
+        ) * MAX_SHAKE_OFFSET
+            * amount
+            / 1.5;
+        let roll = (t * 29.).sin() * MAX_SHAKE_ROLL * amount;
+        let home = shake.home;
+        *transform = home;
+        transform.translation += offset.extend(0.);
+        transform.rotate_local_z(roll);
+    }
+}
+
+fn update_hit_stop(
+    real_time: Res<Time<Real>>,
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if hit_stop.remaining <= 0. {
+        return;
+    }
+    hit_stop.remaining -= real_time.delta_secs();
+    if hit_stop.remaining <= 0. {
+        time.set_relative_speed(1.);
+    }
+}
+
+fn fade_screen_flash(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut flash_q: Query<(Entity, &mut ScreenFlash, &mut BackgroundColor)>,
+) {
+    for (entity, mut flash, mut color) in &mut flash_q {
+        flash.0.tick(time.delta());
+        if flash.0.finished() {
+            commands.entity(entity).despawn();
+        } else {
+            color.0 = Color::WHITE.with_alpha(FLASH_ALPHA * flash.0.fraction_remaining());
+        }
+    }
+}
+
+// Leaving mid hit-stop or mid shake shouldn't carry over into menus
+fn reset_camera_effects(
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+    mut camera_q: Query<&mut CameraShake>,
+) {
+    hit_stop.remaining = 0.;
+    time.set_relative_speed(1.);
+    for mut shake in &mut camera_q {
+        shake.trauma = 0.;
+    }
+}
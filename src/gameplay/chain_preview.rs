@@ -0,0 +1,182 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+
+use crate::{
+    color_palette::ColorPalette,
+    gameplay::{
+        enemy::{Enemy, EnemyClass, EnemyTeam},
+        explosion::ExplosionChain,
+        level::{LevelState, LevelStats},
+    },
+    settings::Settings,
+};
+
+// Labels sit just above the enemy they belong to
+const LABEL_OFFSET: f32 = 26.;
+
+pub struct ChainPreviewPlugin;
+
+impl Plugin for ChainPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(LevelState::Playing), spawn_chain_preview_overlay)
+            .add_systems(
+                Update,
+                (draw_chain_preview, update_chain_preview_labels)
+                    .run_if(in_state(LevelState::Playing)),
+            );
+    }
+}
+
+#[derive(Component, Debug)]
+struct ChainPreviewOverlay;
+
+#[derive(Component, Debug)]
+struct ChainPreviewLabel {
+    enemy: Entity,
+}
+
+// When an enemy's wave goes off, counting waves from 1 for the next one
+#[derive(Clone, Copy, Debug)]
+struct PendingWave {
+    index: usize,
+    seconds: f32,
+}
+
+// The soonest wave each team and class will go off in, across every running chain
+fn pending_waves(
+    chain_q: &Query<&ExplosionChain>,
+    level_stats: &LevelStats,
+) -> HashMap<(EnemyTeam, EnemyClass), PendingWave> {
+    let mut waves = HashMap::<(EnemyTeam, EnemyClass), PendingWave>::default();
+    for chain in chain_q {
+        for (index, (class, seconds)) in chain.upcoming_waves(level_stats).into_iter().enumerate() {
+            let wave = PendingWave {
+                index: index + 1,
+                seconds,
+            };
+            waves
+                .entry((chain.team(), class))
+                .and_modify(|existing| {
+                    if wave.seconds < existing.seconds {
+                        *existing = wave;
+                    }
+                })
+                .or_insert(wave);
+        }
+    }
+    waves
+}
+
+impl PendingWave {
+    fn label(&self) -> String {
+        format!("{} · {:.1}", self.index, self.seconds)
+    }
+    fn color(&self, palette: ColorPalette) -> Color {
+        if self.index == 1 {
+            palette.danger()
+        } else {
+            palette.warning()
+        }
+    }
+}
+
+fn spawn_chain_preview_overlay(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        Name::new("Chain Preview"),
+        ChainPreviewOverlay,
+        StateScoped(LevelState::Playing),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            ..default()
+        },
+        Pickable::IGNORE,
+    ));
+}
+
+fn draw_chain_preview(
+    mut gizmos: Gizmos,
+    settings: Res<Settings>,
+    chain_q: Query<&ExplosionChain>,
+    level_stats: Single<&LevelStats>,
+    enemy_q: Query<(&EnemyTeam, &EnemyClass, &Transform), With<Enemy>>,
+) {
+    if !settings.chain_preview || chain_q.is_empty() {
+        return;
+    }
+    let waves = pending_waves(&chain_q, &level_stats);
+    for (&team, &class, transform) in &enemy_q {
+        let Some(&wave) = waves.get(&(team, class)) else {
+            continue;
+        };
+        let size = class.size() * transform.scale.truncate();
+        gizmos.rect_2d(
+            Isometry2d::from_translation(transform.translation.truncate()),
+            size + 6.,
+            wave.color(settings.color_palette),
+        );
+    }
+}
+
+// Keeps one label per enemy in a pending wave, placed over it on screen
+fn update_chain_preview_labels(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    chain_q: Query<&ExplosionChain>,
+    level_stats: Single<&LevelStats>,
+    overlay: Single<Entity, With<ChainPreviewOverlay>>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    enemy_q: Query<(Entity, &EnemyTeam, &EnemyClass, &GlobalTransform), With<Enemy>>,
+    mut label_q: Query<(
+        Entity,
+        &ChainPreviewLabel,
+        &mut Node,
+        &mut Text,
+        &mut TextColor,
+    )>,
+) {
+    let waves = if settings.chain_preview {
+        pending_waves(&chain_q, &level_stats)
+    } else {
+        default()
+    };
+    let Ok((camera, camera_transform)) = camera_q.single() else {
+        return;
+    };
+    let mut targets = enemy_q
+        .iter()
+        .filter_map(|(entity, &team, &class, transform)| {
+            let wave = waves.get(&(team, class))?;
+            let position = transform.translation() + Vec3::Y * LABEL_OFFSET;
+            let viewport = camera.world_to_viewport(camera_transform, position).ok()?;
+            Some((entity, (*wave, viewport)))
+        })
+        .collect::<HashMap<_, _>>();
+    for (label, ChainPreviewLabel { enemy }, mut node, mut text, mut color) in &mut label_q {
+        let Some((wave, viewport)) = targets.remove(enemy) else {
+            commands.entity(label).despawn();
+            continue;
+        };
+        node.left = Val::Px(viewport.x);
+        node.top = Val::Px(viewport.y);
+        text.0 = wave.label();
+        color.0 = wave.color(settings.color_palette);
+    }
+    for (enemy, (wave, viewport)) in targets {
+        commands.entity(*overlay).with_child((
+            ChainPreviewLabel { enemy },
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(viewport.x),
+                top: Val::Px(viewport.y),
+                ..default()
+            },
+            Text::new(wave.label()),
+            TextColor(wave.color(settings.color_palette)),
+            ClassList::new_with_classes(["chain-preview-label"]),
+            Pickable::IGNORE,
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::{
+        game_run::GameRun,
+        level::{LevelState, LevelStats, on_level_complete},
+    },
+    localization::Localized,
+    storage,
+};
+
+const DAILY_HISTORY_KEY: &str = "daily_history";
+
+pub struct DailyChallengePlugin;
+
+impl Plugin for DailyChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<DailyHistory>(DAILY_HISTORY_KEY))
+            .add_systems(OnEnter(LevelState::Playing), start_daily_attempt)
+            .add_systems(
+                OnEnter(LevelState::Complete),
+                record_daily_result.after(on_level_complete),
+            );
+    }
+}
+
+pub fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+// FNV-1a over the date so every player gets the same level on the same day
+pub fn seed_for_date(date: &str) -> u64 {
+    date.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DailyOutcome {
+    // Started but left before the level was over, which still uses up the day's attempt
+    Unfinished,
+    Failed,
+    Cleared,
+}
+
+impl DailyOutcome {
+    // Message id argument, see daily-result in assets/locales
+    pub fn key(&self) -> &'static str {
+        match self {
+            DailyOutcome::Unfinished => "unfinished",
+            DailyOutcome::Failed => "failed",
+            DailyOutcome::Cleared => "cleared",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DailyResult {
+    pub outcome: DailyOutcome,
+    pub score: u32,
+    pub time_secs: f32,
+}
+
+impl DailyResult {
+    // The given message includes daily-result, which picks its wording from these
+    pub fn summary(&self, message_id: &str) -> Localized {
+        Localized::new(message_id)
+            .with_arg("outcome", self.outcome.key())
+            .with_arg("score", self.score)
+            .with_arg("secs", format!("{:.2}", self.time_secs))
+    }
+}
+
+// The first attempt of each day keyed by date, oldest first
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DailyHistory(pub BTreeMap<String, DailyResult>);
+
+impl DailyHistory {
+    pub fn result(&self, date: &str) -> Option<&DailyResult> {
+        self.0.get(date)
+    }
+    pub fn has_attempted(&self, date: &str) -> bool {
+        self.0.contains_key(date)
+    }
+    fn save(&self) {
+        storage::save(DAILY_HISTORY_KEY, self);
+    }
+}
+
+// Lives on the daily challenge GameRun entity and tracks whether this play is the one that counts
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub enum DailyAttempt {
+    Counted { date: String },
+    Recorded,
+    Practice,
+}
+
+impl DailyAttempt {
+    pub fn new(date: String, history: &DailyHistory) -> Self {
+        if history.has_attempted(&date) {
+            DailyAttempt::Practice
+        } else {
+            DailyAttempt::Counted { date }
+        }
+    }
+    pub fn summary(&self) -> Localized {
+        match self {
+            DailyAttempt::Counted { .. } | DailyAttempt::Recorded => {
+                Localized::new("daily-attempt-recorded")
+            }
+            DailyAttempt::Practice => Localized::new("daily-attempt-practice"),
+        }
+    }
+}
+
+fn start_daily_attempt(
+    mut history: ResMut<DailyHistory>,
+    attempt_q: Query<&mut DailyAttempt, With<GameRun>>,
+) {
+    for mut attempt in attempt_q {
+        if *attempt == DailyAttempt::Recorded {
+            *attempt = DailyAttempt::Practice;
+            continue;
+        }
+        let DailyAttempt::Counted { date } = &*attempt else {
+            continue;
+        };
+        // Written up front so quitting part way through can't earn a second try
+        history.0.insert(
+            date.clone(),
+            DailyResult {
+                outcome: DailyOutcome::Unfinished,
+                score: 0,
+                time_secs: 0.,
+            },
+        );
+        history.save();
+    }
+}
+
+fn record_daily_result(
+    mut history: ResMut<DailyHistory>,
+    level_stats: Single<&LevelStats>,
+    attempt_q: Query<&mut DailyAttempt, With<GameRun>>,
+) {
+    for mut attempt in attempt_q {
+        let DailyAttempt::Counted { date } = &*attempt else {
+            continue;
+        };
+        let outcome = if level_stats.success == Some(true) {
+            DailyOutcome::Cleared
+        } else {
+            DailyOutcome::Failed
+        };
+        history.0.insert(
+            date.clone(),
+            DailyResult {
+                outcome,
+                score: level_stats.score.total(),
+                time_secs: level_stats.stopwatch.elapsed_secs(),
+            },
+        );
+        history.save();
+        *attempt = DailyAttempt::Recorded;
+    }
+}
@@ -0,0 +1,80 @@
+use avian2d::prelude::*;
+use bevy::{platform::collections::HashSet, prelude::*};
+
+use crate::{
+    gameplay::{
+        enemy::{Enemy, EnemyClass, EnemyTeam},
+        explosion::{Explosion, ExplosionChain, final_explosion_size},
+        game_run::{GameRun, GameRunMode},
+        level::{LevelState, LevelStats},
+    },
+    settings::Settings,
+};
+
+// The reach still to come is drawn fainter than explosions already going off
+const FOOTPRINT_ALPHA: f32 = 0.5;
+
+pub struct DangerZonePlugin;
+
+impl Plugin for DangerZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (draw_explosion_footprints, draw_live_explosions)
+                .run_if(in_state(LevelState::Playing).and(shows_danger_zones)),
+        );
+    }
+}
+
+// Training is where reach gets learned, so it's always on there
+fn shows_danger_zones(settings: Res<Settings>, game_run: Option<Single<&GameRun>>) -> bool {
+    settings.danger_zones
+        || game_run.is_some_and(|game_run| game_run.mode() == GameRunMode::Training)
+}
+
+// Where every enemy waiting on a running chain will reach once its explosion is fully grown
+fn draw_explosion_footprints(
+    mut gizmos: Gizmos,
+    settings: Res<Settings>,
+    chain_q: Query<&ExplosionChain>,
+    level_stats: Single<&LevelStats>,
+    enemy_q: Query<(&EnemyTeam, &EnemyClass, &Transform), With<Enemy>>,
+) {
+    let pending = chain_q
+        .iter()
+        .flat_map(|chain| {
+            chain
+                .upcoming_waves(&level_stats)
+                .into_iter()
+                .map(|(class, _)| (chain.team(), class))
+        })
+        .collect::<HashSet<_>>();
+    if pending.is_empty() {
+        return;
+    }
+    for (&team, &class, transform) in &enemy_q {
+        if !pending.contains(&(team, class)) {
+            continue;
+        }
+        gizmos.rect_2d(
+            Isometry2d::from_translation(transform.translation.truncate()),
+            final_explosion_size(class, transform.scale.truncate()),
+            settings.color_palette.danger().with_alpha(FOOTPRINT_ALPHA),
+        );
+    }
+}
+
+// The collider as it is right now, so the growth can be seen
+fn draw_live_explosions(
+    mut gizmos: Gizmos,
+    settings: Res<Settings>,
+    explosion_q: Query<&ColliderAabb, With<Explosion>>,
+) {
+    for aabb in &explosion_q {
+        gizmos.rect_2d(
+            Isometry2d::from_translation(aabb.center()),
+            aabb.size(),
+            settings.color_palette.danger(),
+        );
+    }
+}
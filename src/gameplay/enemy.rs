@@ -1,5 +1,6 @@
 use avian2d::prelude::*;
 use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     app_state::AppState,
@@ -9,6 +10,7 @@ use crate::{
         explosion::ExplosionChain,
         level::LevelStats,
     },
+    loading::SceneHandles,
 };
 
 pub const ENEMY_BASE_SIZE: Vec2 = Vec2::new(80., 30.);
@@ -34,7 +36,7 @@ impl Plugin for EnemyPlugin {
 #[reflect(Component)]
 pub struct Enemy;
 
-#[derive(Component, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect)]
+#[derive(Component, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 #[reflect(Component)]
 pub enum EnemyClass {
     Base,
@@ -84,9 +86,35 @@ impl EnemyClass {
             Self::Wall => 6,
         }
     }
+    // Translated name, see assets/locales
+    pub fn message_id(&self) -> &'static str {
+        match self {
+            Self::Base => "enemy-class-base",
+            Self::DefenderOne => "enemy-class-defender-one",
+            Self::DefenderTwo => "enemy-class-defender-two",
+            Self::DefenderThree => "enemy-class-defender-three",
+            Self::Land => "enemy-class-land",
+            Self::Shadow => "enemy-class-shadow",
+            Self::Wall => "enemy-class-wall",
+        }
+    }
+    // Collider size before the transform's scale is applied
+    pub fn size(&self) -> Vec2 {
+        match self {
+            Self::Base => ENEMY_BASE_SIZE,
+            Self::DefenderOne | Self::DefenderTwo | Self::DefenderThree => ENEMY_DEFENDER_SIZE,
+            Self::Land => ENEMY_LAND_SIZE,
+            Self::Shadow => ENEMY_SHADOW_SIZE,
+            Self::Wall => ENEMY_WALL_SIZE,
+        }
+    }
+    // Land and Walls are sized by scaling, everything else has a fixed size
+    pub fn is_scalable(&self) -> bool {
+        matches!(self, Self::Land | Self::Wall)
+    }
 }
 
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[derive(Component, Clone, Copy, Debug, Hash, PartialEq, Eq, Reflect)]
 #[reflect(Component)]
 pub enum EnemyTeam {
     Alien,
@@ -172,7 +200,7 @@ pub struct EnemyBundle {
 }
 
 impl EnemyBundle {
-    pub fn new_base(asset_server: &AssetServer, position: Vec2) -> Self {
+    pub fn new_base(scenes: &SceneHandles, position: Vec2) -> Self {
         Self {
             enemy: Enemy,
             name: Name::new("Alien Base"),
@@ -180,10 +208,7 @@ impl EnemyBundle {
             class: EnemyClass::Base,
             // TODO: this should be a marker trait for simplicity and querying, but right now everything is an EnemyBundle
             destruction: EnemyDestruction::Required,
-            scene: SceneRoot(
-                asset_server
-                    .load(GltfAssetLabel::Scene(0).from_asset("enemies/enemy-one-base.glb")),
-            ),
+            scene: scenes.enemy(EnemyClass::Base),
             ap: AttackPoints(1),
             hp: HitPoints(3),
             transform: Transform::from_xyz(position.x, position.y, 2.),
@@ -198,17 +223,14 @@ impl EnemyBundle {
         }
     }
     pub fn new_defender(
-        asset_server: &AssetServer,
+        scenes: &SceneHandles,
         position: Vec2,
         defender_class: &DefenderClass,
     ) -> Self {
-        let (class, scene) = match defender_class {
-            DefenderClass::One => (EnemyClass::DefenderOne, "enemies/enemy-defender-one.glb"),
-            DefenderClass::Two => (EnemyClass::DefenderTwo, "enemies/enemy-defender-two.glb"),
-            DefenderClass::Three => (
-                EnemyClass::DefenderThree,
-                "enemies/enemy-defender-three.glb",
-            ),
+        let class = match defender_class {
+            DefenderClass::One => EnemyClass::DefenderOne,
+            DefenderClass::Two => EnemyClass::DefenderTwo,
+            DefenderClass::Three => EnemyClass::DefenderThree,
         };
         Self {
             enemy: Enemy,
@@ -216,7 +238,7 @@ impl EnemyBundle {
             team: EnemyTeam::Alien,
             class,
             destruction: EnemyDestruction::Required,
-            scene: SceneRoot(asset_server.load(GltfAssetLabel::Scene(0).from_asset(scene))),
+            scene: scenes.enemy(class),
             ap: AttackPoints(1),
             hp: HitPoints(1),
             transform: Transform::from_xyz(position.x, position.y, 3.),
@@ -230,25 +252,23 @@ impl EnemyBundle {
             state_scoped: StateScoped(AppState::Gameplay),
         }
     }
-    pub fn new_primary_defender(asset_server: &AssetServer, position: Vec2) -> Self {
-        Self::new_defender(asset_server, position, &DefenderClass::One)
+    pub fn new_primary_defender(scenes: &SceneHandles, position: Vec2) -> Self {
+        Self::new_defender(scenes, position, &DefenderClass::One)
     }
-    pub fn new_secondary_defender(asset_server: &AssetServer, position: Vec2) -> Self {
-        Self::new_defender(asset_server, position, &DefenderClass::Two)
+    pub fn new_secondary_defender(scenes: &SceneHandles, position: Vec2) -> Self {
+        Self::new_defender(scenes, position, &DefenderClass::Two)
     }
-    pub fn new_tertiary_defender(asset_server: &AssetServer, position: Vec2) -> Self {
-        Self::new_defender(asset_server, position, &DefenderClass::Three)
+    pub fn new_tertiary_defender(scenes: &SceneHandles, position: Vec2) -> Self {
+        Self::new_defender(scenes, position, &DefenderClass::Three)
     }
-    pub fn new_land(asset_server: &AssetServer, position: Vec2, scale: Vec2) -> Self {
+    pub fn new_land(scenes: &SceneHandles, position: Vec2, scale: Vec2) -> Self {
         Self {
             enemy: Enemy,
             name: Name::new("Alien Land"),
             team: EnemyTeam::Alien,
             class: EnemyClass::Land,
             destruction: EnemyDestruction::Required,
-            scene: SceneRoot(
-                asset_server.load(GltfAssetLabel::Scene(0).from_asset("enemies/enemy-land.glb")),
-            ),
+            scene: scenes.enemy(EnemyClass::Land),
             ap: AttackPoints(1),
             hp: HitPoints(1),
             transform: Transform::from_xyz(position.x, position.y, 1.).with_scale(scale.extend(1.)),
@@ -259,16 +279,14 @@ impl EnemyBundle {
             state_scoped: StateScoped(AppState::Gameplay),
         }
     }
-    pub fn new_shadow(asset_server: &AssetServer, position: Vec2) -> Self {
+    pub fn new_shadow(scenes: &SceneHandles, position: Vec2) -> Self {
         Self {
             enemy: Enemy,
             name: Name::new("Alien Defender"),
             team: EnemyTeam::Alien,
             class: EnemyClass::Shadow,
             destruction: EnemyDestruction::Required,
-            scene: SceneRoot(
-                asset_server.load(GltfAssetLabel::Scene(0).from_asset("enemies/enemy-shadow.glb")),
-            ),
+            scene: scenes.enemy(EnemyClass::Shadow),
             ap: AttackPoints(1),
             hp: HitPoints(1),
             transform: Transform::from_xyz(position.x, position.y, 3.),
@@ -282,16 +300,14 @@ impl EnemyBundle {
             state_scoped: StateScoped(AppState::Gameplay),
         }
     }
-    pub fn new_wall(asset_server: &AssetServer, position: Vec2, scale: Vec2) -> Self {
+    pub fn new_wall(scenes: &SceneHandles, position: Vec2, scale: Vec2) -> Self {
         Self {
             enemy: Enemy,
             name: Name::new("Alien Wall"),
             team: EnemyTeam::Alien,
             class: EnemyClass::Wall,
             destruction: EnemyDestruction::Impossible,
-            scene: SceneRoot(
-                asset_server.load(GltfAssetLabel::Scene(0).from_asset("enemies/enemy-wall.glb")),
-            ),
+            scene: scenes.enemy(EnemyClass::Wall),
             ap: AttackPoints(0),
             hp: HitPoints(0),
             transform: Transform::from_xyz(position.x, position.y, 8.).with_scale(scale.extend(1.)),
@@ -305,9 +321,45 @@ impl EnemyBundle {
             state_scoped: StateScoped(AppState::Gameplay),
         }
     }
+    // Scale is ignored by classes that aren't scalable
+    pub fn new_of_class(
+        scenes: &SceneHandles,
+        class: EnemyClass,
+        position: Vec2,
+        scale: Vec2,
+    ) -> Self {
+        match class {
+            EnemyClass::Base => Self::new_base(scenes, position),
+            EnemyClass::DefenderOne => Self::new_primary_defender(scenes, position),
+            EnemyClass::DefenderTwo => Self::new_secondary_defender(scenes, position),
+            EnemyClass::DefenderThree => Self::new_tertiary_defender(scenes, position),
+            EnemyClass::Land => Self::new_land(scenes, position, scale),
+            EnemyClass::Shadow => Self::new_shadow(scenes, position),
+            EnemyClass::Wall => Self::new_wall(scenes, position, scale),
+        }
+    }
     pub fn requires_destruction(&self) -> bool {
         self.destruction == EnemyDestruction::Required
     }
+    pub fn class(&self) -> EnemyClass {
+        self.class
+    }
+    pub fn position(&self) -> Vec2 {
+        self.transform.translation.truncate()
+    }
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+    pub fn scale(&self) -> Vec2 {
+        self.transform.scale.truncate()
+    }
+    pub fn scene(&self) -> SceneRoot {
+        self.scene.clone()
+    }
+    // Size of the collider once the transform's scale is applied
+    pub fn size(&self) -> Vec2 {
+        self.class.size() * self.scale()
+    }
 }
 
 #[derive(Event, Clone, Debug, Reflect)]
@@ -13,9 +13,14 @@ use crate::{
         },
         energy::AttackPoints,
         level::{LevelState, LevelStats},
+        pool::{EntityPool, PoolKey},
     },
+    loading::{ExplosionKind, SceneHandles},
 };
 
+// How much wider and taller an explosion gets than what blew up, over its lifecycle
+pub const EXPLOSION_GROWTH: f32 = 80.;
+
 pub struct ExplosionPlugin;
 
 impl Plugin for ExplosionPlugin {
@@ -34,6 +39,11 @@ impl Plugin for ExplosionPlugin {
 #[reflect(Component)]
 pub struct Explosion;
 
+// The reach an enemy's explosion ends up with once fully grown
+pub fn final_explosion_size(class: EnemyClass, scale: Vec2) -> Vec2 {
+    class.size() * scale + EXPLOSION_GROWTH
+}
+
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
 struct ExplosionLifecycle(Timer);
@@ -44,7 +54,8 @@ struct SourceScale(Vec2);
 fn on_enemy_destroyed(
     trigger: Trigger<EnemyDestroyedEvent>,
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    mut pool: ResMut<EntityPool>,
 ) {
     let EnemyDestroyedEvent {
         class,
@@ -54,45 +65,42 @@ fn on_enemy_destroyed(
         team: _,
     } = trigger.event();
 
-    let (collider, scene) = match class {
-        EnemyClass::Base => (
-            Collider::rectangle(ENEMY_BASE_SIZE.x, ENEMY_BASE_SIZE.y),
-            asset_server
-                .load(GltfAssetLabel::Scene(0).from_asset("explosions/enemy-base-explosion.glb")),
-        ),
-        EnemyClass::DefenderOne
-        | EnemyClass::DefenderTwo
-        | EnemyClass::DefenderThree
-        | EnemyClass::Shadow => (
-            Collider::rectangle(ENEMY_DEFENDER_SIZE.x, ENEMY_DEFENDER_SIZE.y),
-            asset_server
-                .load(GltfAssetLabel::Scene(0).from_asset("explosions/enemy-explosion.glb")),
-        ),
-        EnemyClass::Land => (
-            Collider::rectangle(ENEMY_LAND_SIZE.x, ENEMY_LAND_SIZE.y),
-            asset_server
-                .load(GltfAssetLabel::Scene(0).from_asset("explosions/enemy-land-explosion.glb")),
-        ),
-        EnemyClass::Wall => {
-            warn!("Walls can't be destroyed - this should not be reachable");
-            return;
+    let Some(kind) = ExplosionKind::of_class(*class) else {
+        warn!("Walls can't be destroyed - this should not be reachable");
+        return;
+    };
+    let collider = match kind {
+        ExplosionKind::Base => Collider::rectangle(ENEMY_BASE_SIZE.x, ENEMY_BASE_SIZE.y),
+        ExplosionKind::Land => Collider::rectangle(ENEMY_LAND_SIZE.x, ENEMY_LAND_SIZE.y),
+        ExplosionKind::Defender | ExplosionKind::Player => {
+            Collider::rectangle(ENEMY_DEFENDER_SIZE.x, ENEMY_DEFENDER_SIZE.y)
         }
     };
-    commands.spawn((
-        Explosion,
-        StateScoped(AppState::Gameplay),
-        AttackPoints(1),
-        *class,
-        ExplosionLifecycle(Timer::from_seconds(1., TimerMode::Once)),
-        Name::new("EnemyExplosion"),
-        SceneRoot(scene),
-        SourceScale(*scale),
-        Transform::from_translation(position.extend(5.)),
-        RigidBody::Dynamic,
-        collider,
-        CollisionEventsEnabled,
-        CollisionLayers::new(CollisionLayer::EnemyExplosion, [CollisionLayer::Player]),
-    ));
+    pool.spawn(
+        &mut commands,
+        PoolKey::Explosion(kind),
+        (
+            *class,
+            ExplosionLifecycle(Timer::from_seconds(1., TimerMode::Once)),
+            SourceScale(*scale),
+            Transform::from_translation(position.extend(5.)),
+            LinearVelocity::ZERO,
+            AngularVelocity::ZERO,
+        ),
+        || {
+            (
+                Explosion,
+                StateScoped(AppState::Gameplay),
+                AttackPoints(1),
+                Name::new("EnemyExplosion"),
+                scenes.explosion(kind),
+                RigidBody::Dynamic,
+                collider,
+                CollisionEventsEnabled,
+                CollisionLayers::new(CollisionLayer::EnemyExplosion, [CollisionLayer::Player]),
+            )
+        },
+    );
 }
 
 #[derive(Event, Clone, Debug, Default, Reflect)]
@@ -136,6 +144,22 @@ impl ExplosionChain {
     pub fn is_complete(&self) -> bool {
         self.stage.as_ref().is_none()
     }
+    pub fn team(&self) -> EnemyTeam {
+        self.team
+    }
+    // Every wave still to go off, in order, with the seconds until it does
+    pub fn upcoming_waves(&self, level_stats: &LevelStats) -> Vec<(EnemyClass, f32)> {
+        let interval = self.timer.duration().as_secs_f32();
+        let mut seconds = self.timer.remaining_secs();
+        let mut waves = vec![];
+        let mut stage = self.stage;
+        while let Some(class) = stage {
+            waves.push((class, seconds));
+            seconds += interval;
+            stage = Self::following_class(&class, level_stats);
+        }
+        waves
+    }
     pub fn tick(
         &mut self,
         delta: Duration,
@@ -198,6 +222,7 @@ fn on_explosion_chain_event(
 
 fn update_explosion(
     mut commands: Commands,
+    mut pool: ResMut<EntityPool>,
     time: Res<Time>,
     mut explosions_q: Query<
         (
@@ -213,7 +238,9 @@ fn update_explosion(
     for (entity, mut explosion_lifecycle, mut transform, source_scale, class) in &mut explosions_q {
         explosion_lifecycle.0.tick(time.delta());
         if explosion_lifecycle.0.just_finished() {
-            commands.entity(entity).despawn();
+            if let Some(kind) = ExplosionKind::of_class(*class) {
+                pool.release(&mut commands, PoolKey::Explosion(kind), entity);
+            }
         } else {
             let mesh_size = match class {
                 EnemyClass::Base => ENEMY_BASE_SIZE,
@@ -229,7 +256,7 @@ fn update_explosion(
             };
             let pixel_in_scale = 1. / mesh_size;
             transform.scale = (source_scale.0
-                + (pixel_in_scale * 80. * explosion_lifecycle.0.fraction()))
+                + (pixel_in_scale * EXPLOSION_GROWTH * explosion_lifecycle.0.fraction()))
             .extend(1.);
         }
     }
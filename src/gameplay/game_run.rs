@@ -1,14 +1,18 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    gameplay::level::LevelConfig,
+    gameplay::{daily_challenge::seed_for_date, level::LevelConfig},
     levels::{
+        daily, endless,
         game::{
             choose_01, choose_02, corridor_01, corridor_02, in_yer_face_1, in_yer_face_2,
             in_yer_face_3, path_01, path_02, path_03,
         },
+        level_file::LevelFile,
         training::{base, defenders, land, secondary, shadow, wall},
     },
+    loading::SceneHandles,
 };
 
 pub struct GameRunPlugin;
@@ -27,27 +31,88 @@ pub enum LevelStatus {
     Completed,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GameRunMode {
     Training,
     Game,
     SingleLevel,
+    TimeAttack,
+    Endless,
+    DailyChallenge,
+    Playtest,
+    LevelPack,
+}
+
+impl GameRunMode {
+    // Message id argument, see high-scores-run-title in assets/locales
+    pub fn key(&self) -> &'static str {
+        match self {
+            GameRunMode::Training => "training",
+            GameRunMode::Game => "game",
+            GameRunMode::SingleLevel => "single-level",
+            GameRunMode::TimeAttack => "time-attack",
+            GameRunMode::Endless => "endless",
+            GameRunMode::DailyChallenge => "daily-challenge",
+            GameRunMode::Playtest => "playtest",
+            GameRunMode::LevelPack => "level-pack",
+        }
+    }
+    // Time attack keeps the clock running by skipping the countdown and dialogs between levels
+    pub fn auto_advances(&self) -> bool {
+        matches!(self, GameRunMode::TimeAttack)
+    }
+    // Levels being tried out in the editor shouldn't earn stars or high scores, and level packs
+    // keep their own progress so their level names can't clash with the campaign's
+    pub fn keeps_records(&self) -> bool {
+        !matches!(self, GameRunMode::Playtest | GameRunMode::LevelPack)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LevelResult {
+    pub score: u32,
+    pub time_secs: f32,
 }
 
 #[derive(Resource, Debug, Default)]
 pub struct SelectedGameRunMode(pub Option<GameRunMode>);
 
+#[derive(Clone, Debug)]
+enum LevelSequence {
+    // TODO: instead of passing around config methods to avoid useless resource usage, instantiate enemies on demand
+    Fixed(Vec<fn(&SceneHandles) -> LevelConfig>),
+    // Never runs out - each level is built from the seed and its number when it is reached
+    Generated {
+        seed: u64,
+    },
+    // A single level built from the date's seed
+    Daily {
+        date: String,
+    },
+    // Levels that exist only as data, such as the one open in the editor or from a level pack
+    Files(Vec<LevelFile>),
+    // A level imported from glTF, known by its path since its name is whatever the file says
+    Imported {
+        path: &'static str,
+        level_file: LevelFile,
+    },
+}
+
 #[derive(Component, Clone, Debug)]
 pub struct GameRun {
     index: usize,
+    // Statuses and results grow as a generated sequence is played through
     level_statuses: Vec<LevelStatus>,
-    // TODO: instead of passing around config methods to avoid useless resource usage, instantiate enemies on demand
-    levels: Vec<fn(&AssetServer) -> LevelConfig>,
+    // Best result for each level so replays don't add up
+    level_results: Vec<Option<LevelResult>>,
+    levels: LevelSequence,
     mode: GameRunMode,
+    // Every attempt counts, including lost levels and replays
+    elapsed_secs: f32,
 }
 
 impl GameRun {
-    pub fn game_levels() -> Vec<fn(&AssetServer) -> LevelConfig> {
+    pub fn game_levels() -> Vec<fn(&SceneHandles) -> LevelConfig> {
         vec![
             path_01::get_config,
             corridor_01::get_config,
@@ -61,7 +126,7 @@ impl GameRun {
             path_03::get_config,
         ]
     }
-    pub fn training_levels() -> Vec<fn(&AssetServer) -> LevelConfig> {
+    pub fn training_levels() -> Vec<fn(&SceneHandles) -> LevelConfig> {
         vec![
             base::get_config,
             defenders::get_config,
@@ -76,8 +141,10 @@ impl GameRun {
         Self {
             index: 0,
             level_statuses: vec![LevelStatus::default(); levels.len()],
-            levels,
+            level_results: vec![None; levels.len()],
+            levels: LevelSequence::Fixed(levels),
             mode: GameRunMode::Game,
+            elapsed_secs: 0.,
         }
     }
     pub fn new_training() -> Self {
@@ -85,42 +152,176 @@ impl GameRun {
         Self {
             index: 0,
             level_statuses: vec![LevelStatus::default(); levels.len()],
-            levels,
+            level_results: vec![None; levels.len()],
+            levels: LevelSequence::Fixed(levels),
             mode: GameRunMode::Training,
+            elapsed_secs: 0.,
+        }
+    }
+    pub fn new_time_attack() -> Self {
+        Self {
+            mode: GameRunMode::TimeAttack,
+            ..Self::new_game()
+        }
+    }
+    pub fn new_single_level(level_get_config: fn(&SceneHandles) -> LevelConfig) -> Self {
+        Self {
+            index: 0,
+            level_statuses: vec![LevelStatus::default(); 1],
+            level_results: vec![None; 1],
+            levels: LevelSequence::Fixed(vec![level_get_config]),
+            mode: GameRunMode::SingleLevel,
+            elapsed_secs: 0.,
+        }
+    }
+    pub fn new_endless(seed: u64) -> Self {
+        Self {
+            index: 0,
+            level_statuses: vec![LevelStatus::default(); 1],
+            level_results: vec![None; 1],
+            levels: LevelSequence::Generated { seed },
+            mode: GameRunMode::Endless,
+            elapsed_secs: 0.,
+        }
+    }
+    pub fn new_daily_challenge(date: String) -> Self {
+        Self {
+            index: 0,
+            level_statuses: vec![LevelStatus::default(); 1],
+            level_results: vec![None; 1],
+            levels: LevelSequence::Daily { date },
+            mode: GameRunMode::DailyChallenge,
+            elapsed_secs: 0.,
+        }
+    }
+    pub fn new_single_level_file(level_file: LevelFile) -> Self {
+        Self {
+            index: 0,
+            level_statuses: vec![LevelStatus::default(); 1],
+            level_results: vec![None; 1],
+            levels: LevelSequence::Files(vec![level_file]),
+            mode: GameRunMode::SingleLevel,
+            elapsed_secs: 0.,
         }
     }
-    pub fn new_single_level(level_get_config: fn(&AssetServer) -> LevelConfig) -> Self {
+    pub fn new_imported_level(path: &'static str, level_file: LevelFile) -> Self {
         Self {
             index: 0,
             level_statuses: vec![LevelStatus::default(); 1],
-            levels: vec![level_get_config],
+            level_results: vec![None; 1],
+            levels: LevelSequence::Imported { path, level_file },
             mode: GameRunMode::SingleLevel,
+            elapsed_secs: 0.,
+        }
+    }
+    pub fn new_playtest(level_file: LevelFile) -> Self {
+        Self {
+            mode: GameRunMode::Playtest,
+            ..Self::new_single_level_file(level_file)
+        }
+    }
+    pub fn new_level_pack(levels: Vec<LevelFile>) -> Self {
+        Self {
+            index: 0,
+            level_statuses: vec![LevelStatus::default(); levels.len()],
+            level_results: vec![None; levels.len()],
+            levels: LevelSequence::Files(levels),
+            mode: GameRunMode::LevelPack,
+            elapsed_secs: 0.,
         }
     }
     pub fn mode(&self) -> GameRunMode {
         self.mode
     }
+    // Stars and high scores are kept under this, so an imported level named after a built in one
+    // can't overwrite its records
+    pub fn record_key(&self, level_name: &str) -> String {
+        match &self.levels {
+            LevelSequence::Imported { path, .. } => format!("gltf:{path}"),
+            _ => level_name.to_string(),
+        }
+    }
+    // The date a daily challenge was generated for
+    pub fn daily_date(&self) -> Option<&str> {
+        match &self.levels {
+            LevelSequence::Daily { date } => Some(date),
+            _ => None,
+        }
+    }
     pub fn advance_current_level(&mut self) -> Result<(), String> {
         if self.has_more_levels() {
             self.index += 1;
+            if self.index == self.level_statuses.len() {
+                self.level_statuses.push(LevelStatus::default());
+                self.level_results.push(None);
+            }
             Ok(())
         } else {
             Err("No more levels to advance to.".into())
         }
     }
-    pub fn current_level_config(&self, asset_server: &AssetServer) -> LevelConfig {
-        self.levels[self.index](asset_server)
+    pub fn current_level_config(&self, scenes: &SceneHandles) -> LevelConfig {
+        match &self.levels {
+            LevelSequence::Fixed(levels) => levels[self.index](scenes),
+            LevelSequence::Generated { seed } => {
+                endless::get_config(scenes, *seed, self.index as u32 + 1)
+            }
+            LevelSequence::Daily { date } => daily::get_config(scenes, seed_for_date(date)),
+            LevelSequence::Files(levels) => levels[self.index].to_config(scenes),
+            LevelSequence::Imported { level_file, .. } => level_file.to_config(scenes),
+        }
     }
     pub fn has_more_levels(&self) -> bool {
-        self.index + 1 < self.levels.len()
+        match self.total_level_count() {
+            Some(count) => self.index + 1 < count,
+            None => true,
+        }
     }
     pub fn set_current_level_status(&mut self, level_status: LevelStatus) {
         self.level_statuses[self.index] = level_status;
     }
+    pub fn record_current_level_result(&mut self, result: LevelResult) {
+        let best = &mut self.level_results[self.index];
+        if best.is_none_or(|best| result.score > best.score) {
+            *best = Some(result);
+        }
+    }
+    pub fn total_score(&self) -> u32 {
+        self.level_results
+            .iter()
+            .flatten()
+            .map(|result| result.score)
+            .sum()
+    }
+    pub fn add_elapsed_secs(&mut self, secs: f32) {
+        self.elapsed_secs += secs;
+    }
+    pub fn elapsed_secs(&self) -> f32 {
+        self.elapsed_secs
+    }
+    pub fn is_complete(&self) -> bool {
+        self.total_level_count().is_some()
+            && self
+                .level_statuses
+                .iter()
+                .all(|status| status == &LevelStatus::Completed)
+    }
+    pub fn levels_completed(&self) -> usize {
+        self.level_statuses
+            .iter()
+            .filter(|&&status| status == LevelStatus::Completed)
+            .count()
+    }
     pub fn current_level_index(&self) -> usize {
         self.index
     }
-    pub fn total_level_count(&self) -> usize {
-        self.levels.len()
+    // None when the levels never run out
+    pub fn total_level_count(&self) -> Option<usize> {
+        match &self.levels {
+            LevelSequence::Fixed(levels) => Some(levels.len()),
+            LevelSequence::Generated { .. } => None,
+            LevelSequence::Daily { .. } | LevelSequence::Imported { .. } => Some(1),
+            LevelSequence::Files(levels) => Some(levels.len()),
+        }
     }
 }
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::{
+        game_run::{GameRun, GameRunMode},
+        level::{LevelState, LevelStats, on_level_complete},
+    },
+    storage,
+};
+
+const HIGH_SCORES_KEY: &str = "high_scores";
+const PLAYER_NAME_KEY: &str = "player_name";
+pub const HIGH_SCORE_TABLE_SIZE: usize = 10;
+pub const MAX_PLAYER_NAME_LENGTH: usize = 12;
+
+pub struct HighScoresPlugin;
+
+impl Plugin for HighScoresPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<HighScores>(HIGH_SCORES_KEY))
+            .insert_resource(storage::load::<PlayerName>(PLAYER_NAME_KEY))
+            .init_resource::<NewRecords>()
+            .add_systems(
+                OnEnter(LevelState::Complete),
+                record_high_scores.after(on_level_complete),
+            );
+    }
+}
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+pub struct PlayerName(pub String);
+
+impl Default for PlayerName {
+    fn default() -> Self {
+        Self("MAXX".to_string())
+    }
+}
+
+impl PlayerName {
+    pub fn save(&self) {
+        storage::save(PLAYER_NAME_KEY, self);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Training,
+    Normal,
+}
+
+impl From<GameRunMode> for Difficulty {
+    fn from(mode: GameRunMode) -> Self {
+        match mode {
+            GameRunMode::Training => Difficulty::Training,
+            _ => Difficulty::Normal,
+        }
+    }
+}
+
+impl Difficulty {
+    // Message id argument, see difficulty in assets/locales
+    pub fn key(&self) -> &'static str {
+        match self {
+            Difficulty::Training => "training",
+            Difficulty::Normal => "normal",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HighScoreEntry {
+    pub name: String,
+    pub score: u32,
+    pub time_secs: f32,
+    pub date: String,
+    pub difficulty: Difficulty,
+}
+
+impl HighScoreEntry {
+    pub fn new(name: &PlayerName, score: u32, time_secs: f32, difficulty: Difficulty) -> Self {
+        Self {
+            name: name.0.clone(),
+            score,
+            time_secs,
+            date: chrono::Local::now().format("%Y-%m-%d").to_string(),
+            difficulty,
+        }
+    }
+}
+
+// Sorted best first, ties go to the faster time
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HighScoreTable(pub Vec<HighScoreEntry>);
+
+impl HighScoreTable {
+    // Returns the rank the entry placed at if it made the table
+    pub fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        let rank = self.0.iter().position(|existing| {
+            entry.score > existing.score
+                || (entry.score == existing.score && entry.time_secs < existing.time_secs)
+        });
+        let rank = rank.unwrap_or(self.0.len());
+        if rank >= HIGH_SCORE_TABLE_SIZE {
+            return None;
+        }
+        self.0.insert(rank, entry);
+        self.0.truncate(HIGH_SCORE_TABLE_SIZE);
+        Some(rank)
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HighScores {
+    pub levels: BTreeMap<String, HighScoreTable>,
+    pub campaigns: BTreeMap<GameRunMode, HighScoreTable>,
+    // Keyed by date, every day's challenge is a different level
+    #[serde(default)]
+    pub daily: BTreeMap<String, HighScoreTable>,
+}
+
+impl HighScores {
+    pub fn level(&self, level_name: &str) -> Option<&HighScoreTable> {
+        self.levels.get(level_name)
+    }
+    pub fn campaign(&self, mode: GameRunMode) -> Option<&HighScoreTable> {
+        self.campaigns.get(&mode)
+    }
+    pub fn save(&self) {
+        storage::save(HIGH_SCORES_KEY, self);
+    }
+    // Adds the whole run to its mode's table and returns where it placed
+    fn record_run(&mut self, player_name: &PlayerName, game_run: &GameRun) -> Option<usize> {
+        self.campaigns
+            .entry(game_run.mode())
+            .or_default()
+            .insert(HighScoreEntry::new(
+                player_name,
+                game_run.total_score(),
+                game_run.elapsed_secs(),
+                Difficulty::from(game_run.mode()),
+            ))
+    }
+}
+
+// Where the latest level and campaign results placed so the level complete dialog can celebrate
+#[derive(Resource, Debug, Default)]
+pub struct NewRecords {
+    pub level_rank: Option<usize>,
+    pub campaign_rank: Option<usize>,
+}
+
+// Keeps replaying the final level from adding the same run to the campaign table again
+#[derive(Component, Debug)]
+struct CampaignRecorded;
+
+fn record_high_scores(
+    mut commands: Commands,
+    mut high_scores: ResMut<HighScores>,
+    mut new_records: ResMut<NewRecords>,
+    player_name: Res<PlayerName>,
+    level_stats: Single<&LevelStats>,
+    game_run: Single<(Entity, &GameRun, Has<CampaignRecorded>)>,
+) {
+    let (game_run_entity, game_run, campaign_recorded) = *game_run;
+    *new_records = NewRecords::default();
+    if !game_run.mode().keeps_records() {
+        return;
+    }
+    if level_stats.success != Some(true) {
+        // Endless runs are over with the first loss, so that's when they're recorded
+        if game_run.mode() == GameRunMode::Endless && !campaign_recorded {
+            commands.entity(game_run_entity).insert(CampaignRecorded);
+            new_records.campaign_rank = high_scores.record_run(&player_name, game_run);
+            if new_records.campaign_rank.is_some() {
+                high_scores.save();
+            }
+        }
+        return;
+    }
+    let difficulty = Difficulty::from(game_run.mode());
+    let table = match game_run.daily_date() {
+        Some(date) => high_scores.daily.entry(date.to_string()),
+        None => high_scores
+            .levels
+            .entry(game_run.record_key(&level_stats.name)),
+    };
+    new_records.level_rank = table.or_default().insert(HighScoreEntry::new(
+        &player_name,
+        level_stats.score.total(),
+        level_stats.stopwatch.elapsed_secs(),
+        difficulty,
+    ));
+    if matches!(game_run.mode(), GameRunMode::Game | GameRunMode::Training)
+        && game_run.is_complete()
+        && !campaign_recorded
+    {
+        commands.entity(game_run_entity).insert(CampaignRecorded);
+        new_records.campaign_rank = high_scores.record_run(&player_name, game_run);
+    }
+    if new_records.level_rank.is_some() || new_records.campaign_rank.is_some() {
+        high_scores.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, score: u32, time_secs: f32) -> HighScoreEntry {
+        HighScoreEntry {
+            name: name.to_string(),
+            score,
+            time_secs,
+            date: "2025-01-01".to_string(),
+            difficulty: Difficulty::Normal,
+        }
+    }
+
+    fn names(table: &HighScoreTable) -> Vec<&str> {
+        table.0.iter().map(|entry| entry.name.as_str()).collect()
+    }
+
+    #[test]
+    fn entries_are_sorted_best_first() {
+        let mut table = HighScoreTable::default();
+        assert_eq!(table.insert(entry("B", 200, 10.)), Some(0));
+        assert_eq!(table.insert(entry("A", 300, 10.)), Some(0));
+        assert_eq!(table.insert(entry("C", 100, 10.)), Some(2));
+        assert_eq!(names(&table), ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn ties_go_to_the_faster_time() {
+        let mut table = HighScoreTable::default();
+        table.insert(entry("SLOW", 100, 20.));
+        assert_eq!(table.insert(entry("FAST", 100, 10.)), Some(0));
+        // An exact tie doesn't push out the score that got there first
+        assert_eq!(table.insert(entry("SAME", 100, 10.)), Some(1));
+        assert_eq!(names(&table), ["FAST", "SAME", "SLOW"]);
+    }
+
+    #[test]
+    fn table_is_truncated_to_its_size() {
+        let mut table = HighScoreTable::default();
+        for score in 1..=HIGH_SCORE_TABLE_SIZE as u32 {
+            assert!(table.insert(entry("FILL", score * 10, 10.)).is_some());
+        }
+        assert_eq!(table.insert(entry("LOW", 5, 10.)), None);
+        assert_eq!(table.0.len(), HIGH_SCORE_TABLE_SIZE);
+        assert_eq!(table.insert(entry("TOP", 1000, 10.)), Some(0));
+        assert_eq!(table.0.len(), HIGH_SCORE_TABLE_SIZE);
+        assert_eq!(table.0.last().map(|entry| entry.score), Some(20));
+    }
+}
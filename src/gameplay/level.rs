@@ -1,21 +1,35 @@
 use std::time::Duration;
 
-use bevy::{prelude::*, time::Stopwatch};
+use bevy::{asset::RecursiveDependencyLoadState, prelude::*, time::Stopwatch};
 
 use crate::{
     app_state::AppState,
     gameplay::{
-        enemy::{Enemy, EnemyBundle, EnemyCounts, EnemyDestroyedEvent, EnemyDestruction},
+        daily_challenge::DailyAttempt,
+        enemy::{
+            Enemy, EnemyBundle, EnemyClass, EnemyCounts, EnemyDestroyedEvent, EnemyDestruction,
+            EnemyDestructionSource,
+        },
         explosion::Explosion,
-        game_run::{GameRun, LevelStatus},
+        game_run::{GameRun, LevelResult, LevelStatus},
+        high_scores::NewRecords,
+        level_theme::LevelTheme,
         player::{PlayerDestroyedEvent, player_bundle},
-        stage::{spawn_level_info_panel, spawn_level_stats_panel, spawn_stage},
+        rating::{LevelGoals, StarRecords},
+        score::LevelScore,
+        stage::{
+            StageConfig, StageView, spawn_level_info_panel, spawn_level_stats_panel, spawn_stage,
+        },
+        time_attack::TimeAttackClock,
     },
+    loading::SceneHandles,
     menus::level_complete::spawn_level_complete_menu,
 };
 
 const LEAD_OUT_TIME_SUCCESS: Duration = Duration::from_secs(1);
 const LEAD_OUT_TIME_FAIL: Duration = Duration::from_secs(3);
+// Long enough to see what went wrong before an automatic restart
+const LEAD_OUT_TIME_RESTART: Duration = Duration::from_secs(1);
 
 pub struct LevelPlugin;
 
@@ -50,21 +64,41 @@ pub struct Level;
 
 #[derive(Component, Clone, Debug)]
 pub struct LevelStats {
+    pub name: String,
     pub enemy_counts: EnemyCounts,
     pub original_enemy_counts: EnemyCounts,
+    pub player_enemy_counts: EnemyCounts,
+    // What the player had destroyed when the first Base fell and the chain reaction began
+    pub player_enemy_counts_at_chain: Option<EnemyCounts>,
     pub success: Option<bool>,
     pub stopwatch: Stopwatch,
+    pub score: LevelScore,
+    pub goals: LevelGoals,
 }
 
 impl LevelStats {
-    fn new(original_enemy_counts: EnemyCounts) -> Self {
+    fn new(level_config: &LevelConfig) -> Self {
         Self {
-            original_enemy_counts,
+            name: level_config.name.clone(),
+            original_enemy_counts: level_config.enemy_counts(),
             enemy_counts: EnemyCounts::default(),
+            player_enemy_counts: EnemyCounts::default(),
+            player_enemy_counts_at_chain: None,
             success: None,
             stopwatch: Stopwatch::new(),
+            score: LevelScore::default(),
+            goals: level_config.goals,
         }
     }
+    pub fn stars(&self) -> u8 {
+        self.goals.stars(self)
+    }
+    // Picking off defenders during the chain's wave delay doesn't count as beating them to it
+    pub fn player_enemy_counts_before_chain(&self) -> &EnemyCounts {
+        self.player_enemy_counts_at_chain
+            .as_ref()
+            .unwrap_or(&self.player_enemy_counts)
+    }
 }
 
 fn tick_level_stats_stopwatch(time: Res<Time>, mut level_stats: Single<&mut LevelStats>) {
@@ -73,9 +107,14 @@ fn tick_level_stats_stopwatch(time: Res<Time>, mut level_stats: Single<&mut Leve
 
 pub struct LevelConfig {
     pub enemies: Vec<EnemyBundle>,
-    pub name: &'static str,
-    pub notes: &'static str,
+    pub name: String,
+    // Only built in levels are translated, names from files are shown as they were written
+    pub message_id: Option<&'static str>,
+    pub notes: String,
     pub start_position: Vec2,
+    pub stage: StageConfig,
+    pub theme: LevelTheme,
+    pub goals: LevelGoals,
 }
 
 impl LevelConfig {
@@ -95,21 +134,52 @@ pub enum LevelState {
     Complete,
 }
 
-fn spawn_level(mut commands: Commands, asset_server: Res<AssetServer>, game_run: Single<&GameRun>) {
-    let level_config = game_run.current_level_config(&asset_server);
+fn spawn_level(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    game_run: Single<&GameRun>,
+) {
+    let level_config = game_run.current_level_config(&scenes);
     commands.spawn((
         StateScoped(AppState::Gameplay),
-        LevelStats::new((&level_config.enemies).into()),
+        LevelStats::new(&level_config),
     ));
     spawn_level_info_panel(&mut commands, &asset_server, &level_config, &game_run);
     spawn_level_stats_panel(&mut commands, &asset_server, &level_config.enemy_counts());
-    spawn_stage(&mut commands, &asset_server);
-    commands.spawn(player_bundle(&asset_server, level_config.start_position));
+    commands.insert_resource(StageView::new(
+        level_config.stage,
+        level_config.start_position,
+    ));
+    spawn_stage(
+        &mut commands,
+        &asset_server,
+        &scenes,
+        &level_config.stage,
+        &level_config.theme,
+    );
+    commands.spawn(player_bundle(&scenes, level_config.start_position));
     commands.spawn_batch(level_config.enemies);
 }
 
-fn check_load_status(mut next_state: ResMut<NextState<LevelState>>) {
-    next_state.set(LevelState::Ready);
+// Scenes are preloaded while the game starts, this catches any that are still streaming in
+fn check_load_status(
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<LevelState>>,
+    scene_q: Query<(&SceneRoot, &StateScoped<AppState>)>,
+) {
+    let loading = scene_q
+        .iter()
+        .filter(|(_, state_scoped)| state_scoped.0 == AppState::Gameplay)
+        .any(|(scene_root, _)| {
+            matches!(
+                asset_server.recursive_dependency_load_state(&scene_root.0),
+                RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading
+            )
+        });
+    if !loading {
+        next_state.set(LevelState::Ready);
+    }
 }
 
 fn on_enemy_destroyed(
@@ -118,6 +188,12 @@ fn on_enemy_destroyed(
 ) {
     let event = trigger.event();
     level_stats.enemy_counts.increment(&event.class);
+    if event.destruction_source == EnemyDestructionSource::Player {
+        level_stats.player_enemy_counts.increment(&event.class);
+    }
+    if event.class == EnemyClass::Base && level_stats.player_enemy_counts_at_chain.is_none() {
+        level_stats.player_enemy_counts_at_chain = Some(level_stats.player_enemy_counts.clone());
+    }
 }
 
 fn check_level_complete(
@@ -150,17 +226,38 @@ fn on_player_destroyed(
 #[derive(Resource, Debug, Default, Deref, DerefMut)]
 struct LeadOutTimer(Timer);
 
-fn on_level_complete(
+pub fn on_level_complete(
     mut lead_out_timer: ResMut<LeadOutTimer>,
-    level_stats: Single<&LevelStats>,
+    mut level_stats: Single<&mut LevelStats>,
     mut game_run: Single<&mut GameRun>,
+    mut star_records: ResMut<StarRecords>,
 ) {
-    if level_stats.success == Some(true) {
+    let survived = level_stats.success == Some(true);
+    let elapsed_secs = level_stats.stopwatch.elapsed_secs();
+    level_stats.score.finalize(elapsed_secs, survived);
+    game_run.add_elapsed_secs(elapsed_secs);
+    if survived {
         game_run.set_current_level_status(LevelStatus::Completed);
-        lead_out_timer.set_duration(LEAD_OUT_TIME_SUCCESS);
+        game_run.record_current_level_result(LevelResult {
+            score: level_stats.score.total(),
+            time_secs: elapsed_secs,
+        });
+        if game_run.mode().keeps_records() {
+            star_records.record(&game_run.record_key(&level_stats.name), level_stats.stars());
+            star_records.save();
+        }
+        if game_run.mode().auto_advances() && game_run.has_more_levels() {
+            lead_out_timer.set_duration(Duration::ZERO);
+        } else {
+            lead_out_timer.set_duration(LEAD_OUT_TIME_SUCCESS);
+        }
     } else {
         game_run.set_current_level_status(LevelStatus::Tried);
-        lead_out_timer.set_duration(LEAD_OUT_TIME_FAIL);
+        if game_run.mode().auto_advances() {
+            lead_out_timer.set_duration(LEAD_OUT_TIME_RESTART);
+        } else {
+            lead_out_timer.set_duration(LEAD_OUT_TIME_FAIL);
+        }
     }
     lead_out_timer.reset();
 }
@@ -169,14 +266,49 @@ fn update_lead_out_timer(
     commands: Commands,
     time: Res<Time>,
     asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
     mut lead_out_timer: ResMut<LeadOutTimer>,
+    mut next_state: ResMut<NextState<AppState>>,
     level_stats: Single<&LevelStats>,
-    game_run: Single<&mut GameRun>,
+    game_run: Single<(
+        &mut GameRun,
+        Option<&TimeAttackClock>,
+        Option<&DailyAttempt>,
+    )>,
+    star_records: Res<StarRecords>,
+    new_records: Res<NewRecords>,
 ) {
+    let (mut game_run, time_attack_clock, daily_attempt) = game_run.into_inner();
     if !lead_out_timer.finished() {
         lead_out_timer.tick(time.delta());
         if lead_out_timer.just_finished() {
-            spawn_level_complete_menu(commands, &asset_server, &level_stats, &game_run);
+            let survived = level_stats.success == Some(true);
+            if game_run.mode().auto_advances() && (!survived || game_run.has_more_levels()) {
+                let advanced = if survived {
+                    game_run.advance_current_level()
+                } else {
+                    Ok(())
+                };
+                match advanced {
+                    Ok(()) => next_state.set(AppState::ResetGameplay),
+                    Err(message) => warn!(message),
+                }
+                return;
+            }
+            let run_summary = time_attack_clock
+                .map(TimeAttackClock::summary)
+                .or_else(|| daily_attempt.map(|attempt| vec![attempt.summary()]))
+                .unwrap_or_default();
+            spawn_level_complete_menu(
+                commands,
+                &asset_server,
+                &scenes,
+                &level_stats,
+                &game_run,
+                &star_records,
+                &new_records,
+                run_summary,
+            );
         }
     }
 }
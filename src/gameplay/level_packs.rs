@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        game_run::GameRun,
+        level::{LevelState, LevelStats, on_level_complete},
+    },
+    levels::{
+        level_pack::{self, LevelPack},
+        validation::validate,
+    },
+    loading::SceneHandles,
+    storage,
+};
+
+const PACK_PROGRESS_KEY: &str = "pack_progress";
+
+pub struct LevelPacksPlugin;
+
+impl Plugin for LevelPacksPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<PackProgress>(PACK_PROGRESS_KEY))
+            .init_resource::<LevelPacks>()
+            .add_systems(OnEnter(AppState::Loading), discover_level_packs)
+            .add_systems(
+                OnEnter(LevelState::Complete),
+                record_pack_progress.after(on_level_complete),
+            );
+    }
+}
+
+// Packs found in the mods directory, along with why any others couldn't be loaded
+#[derive(Resource, Debug, Default)]
+pub struct LevelPacks {
+    pub packs: Vec<LevelPack>,
+    pub problems: Vec<String>,
+}
+
+// Best stars for each cleared level keyed by pack folder and then level name, kept apart from
+// the campaign's star records
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PackProgress(pub BTreeMap<String, BTreeMap<String, u8>>);
+
+impl PackProgress {
+    pub fn levels_cleared(&self, pack: &LevelPack) -> usize {
+        let Some(cleared) = self.0.get(&pack.id) else {
+            return 0;
+        };
+        pack.levels
+            .iter()
+            .filter(|level| cleared.contains_key(&level.name))
+            .count()
+    }
+    // Earned and available stars over the pack's current levels
+    pub fn stars(&self, pack: &LevelPack) -> (u32, u32) {
+        let cleared = self.0.get(&pack.id);
+        pack.levels
+            .iter()
+            .fold((0, 0), |(earned, available), level| {
+                let best = cleared
+                    .and_then(|cleared| cleared.get(&level.name))
+                    .copied()
+                    .unwrap_or(0);
+                (
+                    earned + best as u32,
+                    available + level.goals.max_stars() as u32,
+                )
+            })
+    }
+    fn save(&self) {
+        storage::save(PACK_PROGRESS_KEY, self);
+    }
+}
+
+// Lives on a level pack's GameRun entity so progress is recorded against the right pack
+#[derive(Component, Clone, Debug)]
+pub struct LevelPackRun {
+    pub pack_id: String,
+}
+
+fn discover_level_packs(mut level_packs: ResMut<LevelPacks>, scenes: Res<SceneHandles>) {
+    *level_packs = LevelPacks::default();
+    for result in level_pack::discover_level_packs() {
+        match result {
+            Ok(pack) => {
+                info!(
+                    "Found level pack \"{}\" with {} levels",
+                    pack.name,
+                    pack.levels.len()
+                );
+                // Packs are offered even with broken levels so their authors can try them, the log
+                // is where they find out what to fix
+                for level in &pack.levels {
+                    for problem in validate(&level.to_config(&scenes)) {
+                        if problem.is_error() {
+                            warn!("Level pack {} \"{}\": {problem}", pack.id, level.name);
+                        } else {
+                            info!("Level pack {} \"{}\": {problem}", pack.id, level.name);
+                        }
+                    }
+                }
+                level_packs.packs.push(pack);
+            }
+            Err(error) => {
+                warn!("Could not load level pack {error}");
+                level_packs.problems.push(error.to_string());
+            }
+        }
+    }
+}
+
+fn record_pack_progress(
+    mut progress: ResMut<PackProgress>,
+    level_stats: Single<&LevelStats>,
+    pack_run_q: Query<&LevelPackRun, With<GameRun>>,
+) {
+    if level_stats.success != Some(true) {
+        return;
+    }
+    for pack_run in pack_run_q {
+        let best = progress
+            .0
+            .entry(pack_run.pack_id.clone())
+            .or_default()
+            .entry(level_stats.name.clone())
+            .or_default();
+        *best = (*best).max(level_stats.stars());
+        progress.save();
+    }
+}
@@ -0,0 +1,292 @@
+use std::path::{Component, Path};
+
+use bevy::{
+    asset::LoadState, color::ColorToPacked, ecs::query::QueryFilter,
+    platform::collections::HashMap, prelude::*, scene::SceneInstanceReady,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{app_state::AppState, loading::SceneHandles};
+
+pub const DEFAULT_AMBIENT_LIGHT: f32 = 1000.;
+
+pub struct LevelThemePlugin;
+
+impl Plugin for LevelThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TintedMaterials>()
+            .add_observer(tint_background)
+            .add_systems(OnExit(AppState::Gameplay), reset_theme)
+            .add_systems(Update, fall_back_to_generic_background);
+    }
+}
+
+// How a level looks, so chapters and teams can be told apart at a glance
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LevelTheme {
+    // A glTF under assets, the generic background is used when missing or unreadable
+    pub background: Option<String>,
+    pub ambient_light: f32,
+    pub palette: Palette,
+}
+
+impl Default for LevelTheme {
+    fn default() -> Self {
+        Self {
+            background: None,
+            ambient_light: DEFAULT_AMBIENT_LIGHT,
+            palette: Palette::Generic,
+        }
+    }
+}
+
+impl LevelTheme {
+    pub fn palette(palette: Palette) -> Self {
+        Self {
+            palette,
+            ..default()
+        }
+    }
+    // The asset server refuses paths leaving assets and the handle it hands back never loads
+    pub fn is_asset_path(path: &str) -> bool {
+        !path.is_empty()
+            && Path::new(path)
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+    }
+    // The background to load instead of the generic one, if it can be
+    pub fn background_path(&self) -> Option<&str> {
+        self.background
+            .as_deref()
+            .filter(|path| Self::is_asset_path(path))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Palette {
+    #[default]
+    Generic,
+    Dusk,
+    Frost,
+    Ember,
+    Toxic,
+}
+
+impl Palette {
+    pub fn all() -> [Self; 5] {
+        [
+            Self::Generic,
+            Self::Dusk,
+            Self::Frost,
+            Self::Ember,
+            Self::Toxic,
+        ]
+    }
+    fn clear_color(&self) -> Color {
+        match self {
+            Self::Generic => ClearColor::default().0,
+            Self::Dusk => Color::srgb(0.12, 0.08, 0.18),
+            Self::Frost => Color::srgb(0.08, 0.13, 0.2),
+            Self::Ember => Color::srgb(0.18, 0.07, 0.05),
+            Self::Toxic => Color::srgb(0.07, 0.14, 0.07),
+        }
+    }
+    fn ambient_color(&self) -> Color {
+        match self {
+            Self::Generic => Color::WHITE,
+            Self::Dusk => Color::srgb(0.9, 0.8, 1.),
+            Self::Frost => Color::srgb(0.8, 0.9, 1.),
+            Self::Ember => Color::srgb(1., 0.85, 0.75),
+            Self::Toxic => Color::srgb(0.85, 1., 0.8),
+        }
+    }
+    // Multiplied into the background's materials
+    fn tint(&self) -> Option<Color> {
+        match self {
+            Self::Generic => None,
+            Self::Dusk => Some(Color::srgb(0.75, 0.6, 0.95)),
+            Self::Frost => Some(Color::srgb(0.65, 0.85, 1.)),
+            Self::Ember => Some(Color::srgb(1., 0.6, 0.45)),
+            Self::Toxic => Some(Color::srgb(0.6, 1., 0.55)),
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct StageBackground {
+    tint: Option<Color>,
+}
+
+pub fn background_bundle(
+    asset_server: &AssetServer,
+    scenes: &SceneHandles,
+    theme: &LevelTheme,
+) -> impl Bundle {
+    let scene = match theme.background_path() {
+        Some(path) => {
+            SceneRoot(asset_server.load(GltfAssetLabel::Scene(0).from_asset(path.to_string())))
+        }
+        None => {
+            if let Some(path) = &theme.background {
+                warn!("Background {path:?} is not a path inside assets, using the generic one");
+            }
+            scenes.background()
+        }
+    };
+    (
+        StageBackground {
+            tint: theme.palette.tint(),
+        },
+        scene,
+    )
+}
+
+pub fn apply_theme(commands: &mut Commands, theme: &LevelTheme) {
+    commands.insert_resource(AmbientLight {
+        color: theme.palette.ambient_color(),
+        brightness: theme.ambient_light,
+        ..default()
+    });
+    commands.insert_resource(ClearColor(theme.palette.clear_color()));
+}
+
+fn reset_theme(mut commands: Commands) {
+    apply_theme(&mut commands, &LevelTheme::default());
+}
+
+fn fall_back_to_generic_background(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    background_q: Query<(Entity, &SceneRoot), With<StageBackground>>,
+) {
+    for (entity, scene_root) in &background_q {
+        // A load that never started won't ever finish either
+        let load_state = asset_server.load_state(&scene_root.0);
+        if load_state.is_failed() || matches!(load_state, LoadState::NotLoaded) {
+            warn!(
+                "Could not load background {:?}, using the generic one",
+                scene_root.0.path()
+            );
+            commands.entity(entity).insert(scenes.background());
+        }
+    }
+}
+
+fn tint_background(
+    trigger: Trigger<SceneInstanceReady>,
+    background_q: Query<&StageBackground>,
+    children_q: Query<&Children>,
+    material_q: Query<&mut MeshMaterial3d<StandardMaterial>>,
+    materials: ResMut<Assets<StandardMaterial>>,
+    tinted_materials: ResMut<TintedMaterials>,
+) {
+    let Ok(StageBackground { tint: Some(tint) }) = background_q.get(trigger.target()) else {
+        return;
+    };
+    tint_scene_materials(
+        trigger.target(),
+        MaterialTint::multiply(*tint),
+        &children_q,
+        material_q,
+        materials,
+        tinted_materials,
+    );
+}
+
+// Colours are kept as bytes so tints can key the material cache
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MaterialTint {
+    // The material's own colour still shows through, for backgrounds
+    Multiply([u8; 4]),
+    // Every part ends up the tint colour, for when the colour itself carries meaning
+    Replace([u8; 4]),
+}
+
+impl MaterialTint {
+    pub fn multiply(tint: Color) -> Self {
+        Self::Multiply(tint.to_srgba().to_u8_array())
+    }
+    pub fn replace(tint: Color) -> Self {
+        Self::Replace(tint.to_srgba().to_u8_array())
+    }
+    fn apply(&self, base_color: Color) -> Color {
+        match *self {
+            Self::Multiply(tint) => {
+                let tint = Srgba::from_u8_array(tint).to_linear();
+                let base = base_color.to_linear();
+                LinearRgba::new(
+                    base.red * tint.red,
+                    base.green * tint.green,
+                    base.blue * tint.blue,
+                    base.alpha,
+                )
+                .into()
+            }
+            Self::Replace(tint) => Srgba::from_u8_array(tint)
+                .with_alpha(base_color.alpha())
+                .into(),
+        }
+    }
+}
+
+// One tinted copy of each material for each tint, shared by every scene instance that needs it
+#[derive(Resource, Debug, Default)]
+pub struct TintedMaterials(
+    HashMap<(AssetId<StandardMaterial>, MaterialTint), Handle<StandardMaterial>>,
+);
+
+// The shared asset is left alone and the scene's meshes are pointed at tinted copies instead
+pub fn tint_scene_materials<F: QueryFilter>(
+    root: Entity,
+    tint: MaterialTint,
+    children_q: &Query<&Children>,
+    mut material_q: Query<&mut MeshMaterial3d<StandardMaterial>, F>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tinted_materials: ResMut<TintedMaterials>,
+) {
+    for descendant in children_q.iter_descendants(root) {
+        let Ok(mut material) = material_q.get_mut(descendant) else {
+            continue;
+        };
+        let key = (material.0.id(), tint);
+        let tinted = match tinted_materials.0.get(&key) {
+            Some(tinted) => tinted.clone(),
+            None => {
+                let Some(mut tinted) = materials.get(&material.0).cloned() else {
+                    continue;
+                };
+                tinted.base_color = tint.apply(tinted.base_color);
+                let tinted = materials.add(tinted);
+                tinted_materials.0.insert(key, tinted.clone());
+                tinted
+            }
+        };
+        material.0 = tinted;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backgrounds_stay_inside_assets() {
+        assert!(LevelTheme::is_asset_path("models/backgrounds/dusk.glb"));
+        assert!(!LevelTheme::is_asset_path(""));
+        assert!(!LevelTheme::is_asset_path("/tmp/background.glb"));
+        assert!(!LevelTheme::is_asset_path("../background.glb"));
+        assert!(!LevelTheme::is_asset_path("models/../../background.glb"));
+        assert!(!LevelTheme::is_asset_path("./background.glb"));
+    }
+
+    #[test]
+    fn unsafe_background_falls_back_to_generic() {
+        let theme = LevelTheme {
+            background: Some("../../background.glb".to_string()),
+            ..default()
+        };
+        assert_eq!(theme.background_path(), None);
+    }
+}
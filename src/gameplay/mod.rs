@@ -1,8 +1,22 @@
+pub mod camera_effects;
+pub mod chain_preview;
 pub mod collisions;
+pub mod daily_challenge;
+pub mod danger_zone;
 pub mod enemy;
 pub mod energy;
 pub mod explosion;
 pub mod game_run;
+pub mod high_scores;
 pub mod level;
+pub mod level_packs;
+pub mod level_theme;
+pub mod particles;
 pub mod player;
+pub mod pool;
+pub mod rating;
+pub mod score;
 pub mod stage;
+pub mod stage_bounds;
+pub mod time_attack;
+pub mod wave_markers;
@@ -0,0 +1,403 @@
+use std::f32::consts::TAU;
+
+use bevy::{ecs::system::SystemParam, platform::collections::HashMap, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        enemy::EnemyDestroyedEvent,
+        player::{Move, PLAYER_SIZE, Player, PlayerDestroyedEvent, PlayerProjectile},
+        pool::{EntityPool, PoolKey},
+    },
+    levels::endless::LevelRng,
+    settings::Settings,
+};
+
+// Above everything on the stage so particles are never hidden behind the ships
+const PARTICLE_Z: f32 = 40.;
+// However busy things get, this many particles are the most alive at once
+const MAX_PARTICLES: usize = 800;
+const TRAIL_INTERVAL_SECS: f32 = 0.03;
+const THRUST_INTERVAL_SECS: f32 = 0.025;
+
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParticleAssets>()
+            .init_resource::<ParticleBudget>()
+            .insert_resource(ParticleRng(LevelRng::new(0x5eed)))
+            .insert_resource(EmitterClocks {
+                trail: Timer::from_seconds(TRAIL_INTERVAL_SECS, TimerMode::Repeating),
+                thrust: Timer::from_seconds(THRUST_INTERVAL_SECS, TimerMode::Repeating),
+            })
+            .add_observer(burst_on_enemy_destroyed)
+            .add_observer(burst_on_player_destroyed)
+            .add_observer(emit_thrust)
+            .add_systems(
+                Update,
+                (
+                    refresh_particle_budget,
+                    tick_emitter_clocks,
+                    emit_projectile_trails,
+                    update_particles,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Gameplay)),
+            );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParticleKind {
+    Debris,
+    Spark,
+    Smoke,
+    Shockwave,
+    Trail,
+    Thrust,
+}
+
+impl ParticleKind {
+    fn all() -> [Self; 6] {
+        [
+            Self::Debris,
+            Self::Spark,
+            Self::Smoke,
+            Self::Shockwave,
+            Self::Trail,
+            Self::Thrust,
+        ]
+    }
+    fn mesh(&self) -> Mesh {
+        match self {
+            Self::Debris => Cuboid::new(1., 1., 1.).into(),
+            // Stretched along the direction of travel
+            Self::Spark => Cuboid::new(1., 0.25, 0.25).into(),
+            Self::Smoke | Self::Trail | Self::Thrust => Sphere::new(0.5).into(),
+            Self::Shockwave => Annulus::new(0.85, 1.).into(),
+        }
+    }
+    fn material(&self) -> StandardMaterial {
+        let (color, alpha_mode) = match self {
+            Self::Debris => (Color::srgb(0.55, 0.55, 0.6), AlphaMode::Opaque),
+            Self::Spark => (Color::srgb(1., 0.85, 0.3), AlphaMode::Opaque),
+            Self::Smoke => (Color::srgba(0.35, 0.35, 0.35, 0.5), AlphaMode::Blend),
+            Self::Shockwave => (Color::srgba(1., 0.95, 0.8, 0.6), AlphaMode::Blend),
+            Self::Trail => (Color::srgba(0.5, 0.9, 1., 0.7), AlphaMode::Blend),
+            Self::Thrust => (Color::srgba(1., 0.55, 0.15, 0.8), AlphaMode::Blend),
+        };
+        StandardMaterial {
+            base_color: color,
+            alpha_mode,
+            unlit: true,
+            ..default()
+        }
+    }
+}
+
+// Shared by every particle of a kind, particles change size rather than colour as they age
+#[derive(Resource, Debug)]
+struct ParticleAssets(HashMap<ParticleKind, (Handle<Mesh>, Handle<StandardMaterial>)>);
+
+impl FromWorld for ParticleAssets {
+    fn from_world(world: &mut World) -> Self {
+        let meshes =
+            ParticleKind::all().map(|kind| world.resource_mut::<Assets<Mesh>>().add(kind.mesh()));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        Self(
+            ParticleKind::all()
+                .into_iter()
+                .zip(meshes)
+                .map(|(kind, mesh)| (kind, (mesh, materials.add(kind.material()))))
+                .collect(),
+        )
+    }
+}
+
+// Purely cosmetic, nothing here has a collider
+#[derive(Component, Clone, Debug)]
+pub struct Particle {
+    kind: ParticleKind,
+    velocity: Vec2,
+    // Fraction of velocity kept after a second
+    drag: f32,
+    spin: f32,
+    age: f32,
+    lifetime: f32,
+    start_size: f32,
+    end_size: f32,
+}
+
+#[derive(Resource, Debug)]
+struct ParticleRng(LevelRng);
+
+// Particles spawned this frame are still queued commands, so rather than counting live particles
+// each spawn takes from what's left of the budget
+#[derive(Resource, Debug)]
+struct ParticleBudget {
+    remaining: usize,
+}
+
+impl Default for ParticleBudget {
+    fn default() -> Self {
+        Self {
+            remaining: MAX_PARTICLES,
+        }
+    }
+}
+
+#[derive(Resource, Debug)]
+struct EmitterClocks {
+    trail: Timer,
+    thrust: Timer,
+}
+
+// What a burst or emitter wants spawned, before the quality setting and budget are applied
+struct ParticleSpec {
+    kind: ParticleKind,
+    position: Vec2,
+    velocity: Vec2,
+    drag: f32,
+    spin: f32,
+    lifetime: f32,
+    start_size: f32,
+    end_size: f32,
+}
+
+#[derive(SystemParam)]
+struct Particles<'w, 's> {
+    commands: Commands<'w, 's>,
+    pool: ResMut<'w, EntityPool>,
+    assets: Res<'w, ParticleAssets>,
+    settings: Res<'w, Settings>,
+    rng: ResMut<'w, ParticleRng>,
+    budget: ResMut<'w, ParticleBudget>,
+}
+
+impl Particles<'_, '_> {
+    // How many of a full-quality count to actually spawn
+    fn count(&self, full: usize) -> usize {
+        let scaled = (full as f32 * self.settings.particles.density()).round() as usize;
+        scaled.min(self.budget.remaining)
+    }
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        self.rng.0.range(min, max)
+    }
+    fn direction(&mut self) -> Vec2 {
+        Vec2::from_angle(self.range(0., TAU))
+    }
+    fn spawn(&mut self, spec: ParticleSpec) {
+        // A burst sizes each of its parts before spawning any, together they may not fit
+        if self.budget.remaining == 0 {
+            return;
+        }
+        self.budget.remaining -= 1;
+        let (mesh, material) = self.assets.0[&spec.kind].clone();
+        let rotation = Quat::from_rotation_z(spec.velocity.to_angle());
+        self.pool.spawn(
+            &mut self.commands,
+            PoolKey::Particle(spec.kind),
+            (
+                Particle {
+                    kind: spec.kind,
+                    velocity: spec.velocity,
+                    drag: spec.drag,
+                    spin: spec.spin,
+                    age: 0.,
+                    lifetime: spec.lifetime,
+                    start_size: spec.start_size,
+                    end_size: spec.end_size,
+                },
+                Transform::from_translation(spec.position.extend(PARTICLE_Z))
+                    .with_rotation(rotation)
+                    .with_scale(Vec3::splat(spec.start_size)),
+            ),
+            || {
+                (
+                    Name::new("Particle"),
+                    StateScoped(AppState::Gameplay),
+                    Mesh3d(mesh),
+                    MeshMaterial3d(material),
+                )
+            },
+        );
+    }
+    // Debris, sparks, smoke and a shockwave ring sized to whatever blew up
+    fn explosion(&mut self, position: Vec2, size: f32, intensity: f32) {
+        let count = |full: f32| self.count((full * intensity).round() as usize);
+        let (debris, sparks, smoke) = (count(10.), count(14.), count(6.));
+        let rings = self.count(1);
+        for _ in 0..debris {
+            let velocity = self.direction() * self.range(40., 160.) * intensity;
+            let spec = ParticleSpec {
+                kind: ParticleKind::Debris,
+                position,
+                velocity,
+                drag: 0.2,
+                spin: self.range(-12., 12.),
+                lifetime: self.range(0.5, 1.1),
+                start_size: self.range(3., 6.),
+                end_size: 0.,
+            };
+            self.spawn(spec);
+        }
+        for _ in 0..sparks {
+            let velocity = self.direction() * self.range(180., 360.) * intensity;
+            let spec = ParticleSpec {
+                kind: ParticleKind::Spark,
+                position,
+                velocity,
+                drag: 0.05,
+                spin: 0.,
+                lifetime: self.range(0.15, 0.35),
+                start_size: 10.,
+                end_size: 2.,
+            };
+            self.spawn(spec);
+        }
+        for _ in 0..smoke {
+            let offset = self.direction() * self.range(0., size / 3.);
+            let velocity = self.direction() * self.range(5., 25.);
+            let spec = ParticleSpec {
+                kind: ParticleKind::Smoke,
+                position: position + offset,
+                velocity,
+                drag: 0.3,
+                spin: 0.,
+                lifetime: self.range(0.8, 1.4),
+                start_size: size / 3.,
+                end_size: size * 0.9,
+            };
+            self.spawn(spec);
+        }
+        for _ in 0..rings {
+            self.spawn(ParticleSpec {
+                kind: ParticleKind::Shockwave,
+                position,
+                velocity: Vec2::ZERO,
+                drag: 1.,
+                spin: 0.,
+                lifetime: 0.35,
+                start_size: size / 4.,
+                end_size: size * 1.5 * intensity,
+            });
+        }
+    }
+}
+
+fn burst_on_enemy_destroyed(trigger: Trigger<EnemyDestroyedEvent>, mut particles: Particles) {
+    let event = trigger.event();
+    let size = (event.class.size() * event.scale).max_element();
+    particles.explosion(event.position, size, 1.);
+}
+
+fn burst_on_player_destroyed(
+    trigger: Trigger<PlayerDestroyedEvent>,
+    mut particles: Particles,
+    transform_q: Query<&Transform>,
+) {
+    let Ok(transform) = transform_q.get(trigger.target()) else {
+        return;
+    };
+    particles.explosion(
+        transform.translation.truncate(),
+        PLAYER_SIZE.max_element(),
+        2.,
+    );
+}
+
+fn refresh_particle_budget(
+    mut budget: ResMut<ParticleBudget>,
+    particle_q: Query<(), With<Particle>>,
+) {
+    budget.remaining = MAX_PARTICLES.saturating_sub(particle_q.iter().count());
+}
+
+fn tick_emitter_clocks(time: Res<Time>, mut clocks: ResMut<EmitterClocks>) {
+    clocks.trail.tick(time.delta());
+    clocks.thrust.tick(time.delta());
+}
+
+fn emit_projectile_trails(
+    clocks: Res<EmitterClocks>,
+    mut particles: Particles,
+    projectile_q: Query<&Transform, With<PlayerProjectile>>,
+) {
+    if !clocks.trail.just_finished() {
+        return;
+    }
+    let positions = projectile_q
+        .iter()
+        .map(|transform| transform.translation.truncate())
+        .collect::<Vec<_>>();
+    let count = particles.count(positions.len());
+    for position in positions.into_iter().take(count) {
+        particles.spawn(ParticleSpec {
+            kind: ParticleKind::Trail,
+            position,
+            velocity: Vec2::ZERO,
+            drag: 1.,
+            spin: 0.,
+            lifetime: 0.2,
+            start_size: 5.,
+            end_size: 0.,
+        });
+    }
+}
+
+// Exhaust streams out behind the ship in the opposite direction to the input
+fn emit_thrust(
+    trigger: Trigger<Fired<Move>>,
+    clocks: Res<EmitterClocks>,
+    mut particles: Particles,
+    player_q: Query<&Transform, With<Player>>,
+) {
+    let Some(direction) = trigger.value.try_normalize() else {
+        return;
+    };
+    if !clocks.thrust.just_finished() || particles.count(1) == 0 {
+        return;
+    }
+    let Ok(transform) = player_q.get(trigger.target()) else {
+        return;
+    };
+    let exhaust = -direction;
+    let position = transform.translation.truncate() + exhaust * PLAYER_SIZE.y / 2.;
+    let spread = particles.range(-0.4, 0.4);
+    let velocity = Vec2::from_angle(spread).rotate(exhaust) * particles.range(60., 120.);
+    particles.spawn(ParticleSpec {
+        kind: ParticleKind::Thrust,
+        position,
+        velocity,
+        drag: 0.1,
+        spin: 0.,
+        lifetime: 0.25,
+        start_size: 6.,
+        end_size: 1.,
+    });
+}
+
+fn update_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pool: ResMut<EntityPool>,
+    particle_q: Query<(Entity, &mut Particle, &mut Transform)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, mut transform) in particle_q {
+        particle.age += delta;
+        if particle.age >= particle.lifetime {
+            pool.release(&mut commands, PoolKey::Particle(particle.kind), entity);
+            continue;
+        }
+        let drag = particle.drag.powf(delta);
+        particle.velocity *= drag;
+        transform.translation += (particle.velocity * delta).extend(0.);
+        transform.rotate_z(particle.spin * delta);
+        let t = particle.age / particle.lifetime;
+        transform.scale =
+            Vec3::splat(particle.start_size + (particle.end_size - particle.start_size) * t);
+    }
+}
@@ -10,11 +10,14 @@ use crate::{
         collisions::CollisionLayer,
         energy::{AttackPoints, HitPoints},
         level::LevelState,
-        stage::{STAGE_HEIGHT, STAGE_WIDTH},
+        pool::{EntityPool, PoolKey},
+        stage::StageView,
+        stage_bounds::StageBounds,
     },
+    loading::{ExplosionKind, ProjectileKind, SceneHandles},
 };
 
-const PLAYER_SIZE: Vec2 = Vec2::new(37.8, 38.6);
+pub const PLAYER_SIZE: Vec2 = Vec2::new(37.8, 38.6);
 
 pub struct PlayerPlugin;
 
@@ -46,7 +49,7 @@ impl Plugin for PlayerPlugin {
     }
 }
 
-pub fn player_bundle(asset_server: &AssetServer, position: Vec2) -> impl Bundle {
+pub fn player_bundle(scenes: &SceneHandles, position: Vec2) -> impl Bundle {
     (
         Player,
         Name::new("Player"),
@@ -54,9 +57,7 @@ pub fn player_bundle(asset_server: &AssetServer, position: Vec2) -> impl Bundle
         Speed(200.),
         HitPoints(1),
         AutoFire::new(0.2, false /* TODO: is_firing_active? */),
-        SceneRoot(
-            asset_server.load(GltfAssetLabel::Scene(0).from_asset("player-ship/player-ship.glb")),
-        ),
+        scenes.player(),
         RigidBody::Dynamic,
         Collider::triangle(
             vec2(0., 27.304),
@@ -157,7 +158,8 @@ fn on_spawn_player(
 
 fn fire_player_projectile(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    mut pool: ResMut<EntityPool>,
     time: Res<Time>,
     mut player_q: Query<(&mut AutoFire, &Transform, &PlayerOwnedWeapons), With<Player>>,
     weapons_q: Query<&Transform, (With<PlayerWeapon>, Without<Player>)>,
@@ -165,42 +167,55 @@ fn fire_player_projectile(
     for (mut auto_fire, player_transform, owned_weapons) in &mut player_q {
         auto_fire.tick(time.delta());
         if auto_fire.just_triggered() {
+            commands.trigger(PlayerFiredEvent);
             for weapon in &owned_weapons.0 {
                 let Ok(weapon_transform) = weapons_q.get(*weapon) else {
                     warn!("Could not find PlayerWeapon");
                     continue;
                 };
 
-                commands.spawn((
-                    PlayerProjectile,
-                    Name::new("Player Projectile"),
-                    StateScoped(AppState::Gameplay),
-                    AttackPoints(1),
-                    SceneRoot(asset_server.load(
-                        GltfAssetLabel::Scene(0).from_asset("projectiles/player-projectile.glb"),
-                    )),
-                    Transform::from_translation(
-                        player_transform.translation.xy().extend(0.)
-                            + weapon_transform.translation.xy().extend(24.),
-                    ),
-                    RigidBody::Dynamic,
-                    Collider::circle(4. as Scalar),
-                    CollisionEventsEnabled,
-                    CollisionLayers::new(
-                        CollisionLayer::PlayerProjectile,
-                        [
-                            CollisionLayer::EnemyBase,
-                            CollisionLayer::EnemyDefender,
-                            CollisionLayer::EnemyWall,
-                        ],
+                pool.spawn(
+                    &mut commands,
+                    PoolKey::Projectile(ProjectileKind::Player),
+                    (
+                        Transform::from_translation(
+                            player_transform.translation.xy().extend(0.)
+                                + weapon_transform.translation.xy().extend(24.),
+                        ),
+                        LinearVelocity(vec2(0., 200.)),
+                        AngularVelocity::ZERO,
                     ),
-                    LinearVelocity(vec2(0., 200.)),
-                ));
+                    || {
+                        (
+                            PlayerProjectile,
+                            Name::new("Player Projectile"),
+                            StateScoped(AppState::Gameplay),
+                            AttackPoints(1),
+                            scenes.projectile(ProjectileKind::Player),
+                            RigidBody::Dynamic,
+                            StageBounds::CleanUp,
+                            Collider::circle(4. as Scalar),
+                            CollisionEventsEnabled,
+                            CollisionLayers::new(
+                                CollisionLayer::PlayerProjectile,
+                                [
+                                    CollisionLayer::EnemyBase,
+                                    CollisionLayer::EnemyDefender,
+                                    CollisionLayer::EnemyWall,
+                                ],
+                            ),
+                        )
+                    },
+                );
             }
         }
     }
 }
 
+// One per volley however many weapons the player has
+#[derive(Event, Clone, Debug, Default, Reflect)]
+pub struct PlayerFiredEvent;
+
 #[derive(Event, Clone, Debug, Reflect)]
 pub struct PlayerCollisionEvent {
     attacking_points: AttackPoints,
@@ -245,7 +260,7 @@ pub struct PlayerDestroyedEvent {}
 fn on_player_destroyed(
     trigger: Trigger<PlayerDestroyedEvent>,
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
     player_q: Query<&Transform, With<Player>>,
 ) {
     if let Ok(transform) = player_q.get(trigger.target()) {
@@ -255,9 +270,7 @@ fn on_player_destroyed(
             Name::new("Player Explosion"),
             StateScoped(AppState::Gameplay),
             PlayerExplosionTimer::default(),
-            SceneRoot(asset_server.load(
-                GltfAssetLabel::Scene(0).from_asset("player-explosion/player-explosion.glb"),
-            )),
+            scenes.explosion(ExplosionKind::Player),
             Transform::from_translation(transform.translation.truncate().extend(10.)),
         ));
     } else {
@@ -292,8 +305,13 @@ pub struct PlayerProjectileCollisionEvent {}
 fn on_player_projectile_collision(
     trigger: Trigger<PlayerProjectileCollisionEvent>,
     mut commands: Commands,
+    mut pool: ResMut<EntityPool>,
 ) {
-    commands.entity(trigger.target()).despawn();
+    pool.release(
+        &mut commands,
+        PoolKey::Projectile(ProjectileKind::Player),
+        trigger.target(),
+    );
 }
 
 fn binding(trigger: Trigger<Binding<Playing>>, mut players: Query<&mut Actions<Playing>>) {
@@ -330,44 +348,30 @@ fn remove_input_context(mut commands: Commands, player_q: Query<Entity, With<Pla
 fn apply_movement(
     trigger: Trigger<Fired<Move>>,
     spatial_query: SpatialQuery,
+    view: Res<StageView>,
     mut player_q: Query<(&mut Transform, &Collider, &Speed), With<Player>>,
 ) {
     let Ok((mut transform, collider, speed)) = player_q.get_mut(trigger.target()) else {
         warn!("Can't find Player - skipping movement");
         return;
     };
-    if trigger.value == Vec2::ZERO {
+    let old = transform.translation.truncate();
+    // Kept on screen, which moves on scrolling stages
+    let new = view.keep_inside(old + speed.0 * trigger.value, PLAYER_SIZE);
+    // Cast along the move actually made, since keeping on screen can change it
+    let Ok(direction) = Dir2::new(new - old) else {
         return;
-    }
-    let velocity = speed.0 * trigger.value;
-    let stage_half_width = STAGE_WIDTH / 2. - PLAYER_SIZE.x / 2.;
-    let stage_half_height = STAGE_HEIGHT / 2. - PLAYER_SIZE.y / 2.;
-    let new_x = (transform.translation.x + velocity.x).clamp(-stage_half_width, stage_half_width);
-    let new_y = (transform.translation.y + velocity.y).clamp(-stage_half_height, stage_half_height);
-
-    let config = ShapeCastConfig::from_max_distance(
-        transform
-            .translation
-            .truncate()
-            .distance(vec2(new_x, new_y)),
-    );
+    };
+    let config = ShapeCastConfig::from_max_distance(old.distance(new));
     let filter = SpatialQueryFilter::from_mask(CollisionLayer::EnemyWall);
     if spatial_query
-        .cast_shape(
-            collider,
-            transform.translation.truncate(),
-            0.,
-            Dir2::try_from(trigger.value)
-                .expect("Value from Move should be normalized and non-zero"),
-            &config,
-            &filter,
-        )
+        .cast_shape(collider, old, 0., direction, &config, &filter)
         .is_some()
     {
         return;
     }
-    transform.translation.x = new_x;
-    transform.translation.y = new_y;
+    transform.translation.x = new.x;
+    transform.translation.y = new.y;
 }
 
 fn start_firing(trigger: Trigger<Started<Fire>>, mut player_q: Query<&mut AutoFire, With<Player>>) {
@@ -394,7 +398,7 @@ struct Playing;
 
 #[derive(Debug, InputAction)]
 #[input_action(output = Vec2)]
-struct Move;
+pub struct Move;
 
 #[derive(Debug, InputAction)]
 #[input_action(output = bool)]
@@ -0,0 +1,182 @@
+use bevy::{
+    ecs::entity_disabling::Disabled,
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+
+use crate::{
+    app_state::AppState,
+    gameplay::particles::ParticleKind,
+    loading::{ExplosionKind, ProjectileKind},
+};
+
+pub struct PoolPlugin;
+
+impl Plugin for PoolPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EntityPool>()
+            .add_systems(OnExit(AppState::Gameplay), clear_pool);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PoolKey {
+    Projectile(ProjectileKind),
+    Explosion(ExplosionKind),
+    Particle(ParticleKind),
+}
+
+// Remembers which pool an entity goes back to so code that didn't spawn it can clean it up
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Pooled(pub PoolKey);
+
+// Projectiles and explosions come and go many times a second, so instead of being despawned
+// they're disabled and handed out again with their transform, velocity and timers reset
+#[derive(Resource, Debug)]
+pub struct EntityPool {
+    // Off despawns released entities as before, which the pooling benchmark compares against
+    pub enabled: bool,
+    free: HashMap<PoolKey, Vec<Entity>>,
+    // Everything in free, to catch double releases without searching the lists
+    released: HashSet<Entity>,
+}
+
+impl Default for EntityPool {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            free: HashMap::default(),
+            released: HashSet::default(),
+        }
+    }
+}
+
+impl EntityPool {
+    // The reset bundle is inserted every time, the new bundle only when nothing could be reused
+    // so a reused entity keeps its scene instance and collider
+    pub fn spawn<B: Bundle>(
+        &mut self,
+        commands: &mut Commands,
+        key: PoolKey,
+        reset: impl Bundle,
+        new: impl FnOnce() -> B,
+    ) -> Entity {
+        match self.free.get_mut(&key).and_then(Vec::pop) {
+            Some(entity) => {
+                self.released.remove(&entity);
+                commands
+                    .entity(entity)
+                    .remove_recursive::<Children, Disabled>()
+                    .insert(reset);
+                entity
+            }
+            None => commands.spawn((Pooled(key), new(), reset)).id(),
+        }
+    }
+    pub fn release(&mut self, commands: &mut Commands, key: PoolKey, entity: Entity) {
+        if !self.enabled {
+            commands.entity(entity).despawn();
+            return;
+        }
+        // Hitting two things in the same frame releases a projectile twice
+        if !self.released.insert(entity) {
+            return;
+        }
+        // Scene children are disabled too, otherwise their meshes would still be drawn
+        commands
+            .entity(entity)
+            .insert_recursive::<Children>(Disabled);
+        self.free.entry(key).or_default().push(entity);
+    }
+    // Back to the pool if it came from one, otherwise gone for good
+    pub fn recycle(&mut self, commands: &mut Commands, entity: Entity, pooled: Option<&Pooled>) {
+        match pooled {
+            Some(pooled) => self.release(commands, pooled.0, entity),
+            None => commands.entity(entity).despawn(),
+        }
+    }
+    pub fn free_count(&self) -> usize {
+        self.free.values().map(Vec::len).sum()
+    }
+}
+
+// StateScoped can't see disabled entities, so the pool cleans up after itself
+fn clear_pool(mut commands: Commands, mut pool: ResMut<EntityPool>) {
+    pool.released.clear();
+    for (_, entities) in pool.free.drain() {
+        for entity in entities {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use avian2d::prelude::*;
+
+    use super::*;
+
+    const KEY: PoolKey = PoolKey::Projectile(ProjectileKind::Player);
+
+    fn spawn(world: &mut World, position: Vec3, velocity: Vec2) -> Entity {
+        let entity = world.resource_scope(|world, mut pool: Mut<EntityPool>| {
+            pool.spawn(
+                &mut world.commands(),
+                KEY,
+                (
+                    Transform::from_translation(position),
+                    LinearVelocity(velocity),
+                ),
+                || Name::new("Pooled"),
+            )
+        });
+        world.flush();
+        entity
+    }
+
+    fn release(world: &mut World, entity: Entity) {
+        world.resource_scope(|world, mut pool: Mut<EntityPool>| {
+            pool.release(&mut world.commands(), KEY, entity);
+        });
+        world.flush();
+    }
+
+    #[test]
+    fn released_entities_are_reused_and_reset() {
+        let mut world = World::new();
+        world.init_resource::<EntityPool>();
+        let entity = spawn(&mut world, Vec3::new(10., 20., 0.), Vec2::new(0., 500.));
+        world.get_mut::<Transform>(entity).unwrap().translation = Vec3::new(30., 400., 0.);
+        world.get_mut::<LinearVelocity>(entity).unwrap().0 = Vec2::new(5., -5.);
+
+        release(&mut world, entity);
+        assert!(world.get::<Disabled>(entity).is_some());
+        assert_eq!(world.resource::<EntityPool>().free_count(), 1);
+
+        let reused = spawn(&mut world, Vec3::new(-10., 0., 0.), Vec2::new(0., 250.));
+        assert_eq!(reused, entity);
+        assert!(world.get::<Disabled>(entity).is_none());
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::new(-10., 0., 0.)
+        );
+        assert_eq!(
+            world.get::<LinearVelocity>(entity).unwrap().0,
+            Vec2::new(0., 250.)
+        );
+        assert_eq!(world.resource::<EntityPool>().free_count(), 0);
+    }
+
+    #[test]
+    fn releasing_twice_only_pools_once() {
+        let mut world = World::new();
+        world.init_resource::<EntityPool>();
+        let entity = spawn(&mut world, Vec3::ZERO, Vec2::ZERO);
+        release(&mut world, entity);
+        release(&mut world, entity);
+        assert_eq!(world.resource::<EntityPool>().free_count(), 1);
+
+        assert_eq!(spawn(&mut world, Vec3::ZERO, Vec2::ZERO), entity);
+        assert_ne!(spawn(&mut world, Vec3::ZERO, Vec2::ZERO), entity);
+    }
+}
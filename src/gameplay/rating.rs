@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::{
+        enemy::EnemyClass,
+        level::{LevelConfig, LevelStats},
+    },
+    localization::Localized,
+    storage,
+};
+
+const STAR_RECORDS_KEY: &str = "star_records";
+
+const DEFENDER_CLASSES: [EnemyClass; 3] = [
+    EnemyClass::DefenderOne,
+    EnemyClass::DefenderTwo,
+    EnemyClass::DefenderThree,
+];
+
+pub struct RatingPlugin;
+
+impl Plugin for RatingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<StarRecords>(STAR_RECORDS_KEY));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LevelGoal {
+    // Every Defender is destroyed by the player's projectiles instead of the chain reaction
+    PlayerDestroysDefenders,
+    ChainMultiplier(u32),
+    Score(u32),
+}
+
+impl LevelGoal {
+    pub fn description(&self) -> Localized {
+        let goal = Localized::new("level-goal");
+        match self {
+            LevelGoal::PlayerDestroysDefenders => goal.with_arg("goal", "defenders"),
+            LevelGoal::ChainMultiplier(multiplier) => goal
+                .with_arg("goal", "chain")
+                .with_arg("multiplier", *multiplier),
+            LevelGoal::Score(score) => goal.with_arg("goal", "score").with_arg("score", *score),
+        }
+    }
+    pub fn is_met(&self, level_stats: &LevelStats) -> bool {
+        match self {
+            LevelGoal::PlayerDestroysDefenders => DEFENDER_CLASSES.iter().all(|class| {
+                level_stats.player_enemy_counts_before_chain().count(class)
+                    == level_stats.original_enemy_counts.count(class)
+            }),
+            LevelGoal::ChainMultiplier(multiplier) => level_stats.score.multiplier >= *multiplier,
+            LevelGoal::Score(score) => level_stats.score.total() >= *score,
+        }
+    }
+}
+
+// Clearing a level earns the first star, the par time and goal earn one more each
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct LevelGoals {
+    pub par_time: Option<f32>,
+    pub goal: Option<LevelGoal>,
+}
+
+impl LevelGoals {
+    pub fn new(par_time: f32, goal: LevelGoal) -> Self {
+        Self {
+            par_time: Some(par_time),
+            goal: Some(goal),
+        }
+    }
+    pub fn par_time(par_time: f32) -> Self {
+        Self {
+            par_time: Some(par_time),
+            goal: None,
+        }
+    }
+    pub fn max_stars(&self) -> u8 {
+        1 + self.par_time.is_some() as u8 + self.goal.is_some() as u8
+    }
+    pub fn par_time_met(&self, level_stats: &LevelStats) -> bool {
+        self.par_time
+            .is_some_and(|par_time| level_stats.stopwatch.elapsed_secs() <= par_time)
+    }
+    pub fn goal_met(&self, level_stats: &LevelStats) -> bool {
+        self.goal.is_some_and(|goal| goal.is_met(level_stats))
+    }
+    pub fn stars(&self, level_stats: &LevelStats) -> u8 {
+        if level_stats.success != Some(true) {
+            return 0;
+        }
+        1 + self.par_time_met(level_stats) as u8 + self.goal_met(level_stats) as u8
+    }
+    pub fn description(&self) -> Vec<Localized> {
+        let mut lines = Vec::new();
+        if let Some(par_time) = self.par_time {
+            lines.push(Localized::new("level-goal-par").with_arg("secs", format!("{par_time:.2}")));
+        }
+        if let Some(goal) = self.goal {
+            lines.push(goal.description());
+        }
+        lines
+    }
+}
+
+pub fn star_text(stars: u8, max_stars: u8) -> String {
+    format!(
+        "{}{}",
+        "*".repeat(stars as usize),
+        "-".repeat(max_stars.saturating_sub(stars) as usize)
+    )
+}
+
+// Best stars earned for each level, keyed by level name
+#[derive(Resource, Debug, Default, Serialize, Deserialize)]
+pub struct StarRecords(BTreeMap<String, u8>);
+
+impl StarRecords {
+    pub fn best(&self, level_name: &str) -> u8 {
+        self.0.get(level_name).copied().unwrap_or_default()
+    }
+    pub fn record(&mut self, level_name: &str, stars: u8) {
+        let best = self.0.entry(level_name.to_string()).or_default();
+        *best = (*best).max(stars);
+    }
+    pub fn save(&self) {
+        storage::save(STAR_RECORDS_KEY, self);
+    }
+    // Returns the earned and available stars for a set of levels
+    pub fn total(&self, level_configs: &[LevelConfig]) -> (u32, u32) {
+        level_configs
+            .iter()
+            .fold((0, 0), |(earned, available), level_config| {
+                (
+                    earned + self.best(&level_config.name) as u32,
+                    available + level_config.goals.max_stars() as u32,
+                )
+            })
+    }
+}
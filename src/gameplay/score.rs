@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{
+    enemy::{EnemyClass, EnemyDestroyedEvent, EnemyDestructionSource},
+    explosion::ExplosionChainEvent,
+    level::LevelStats,
+};
+
+// Finishing slower than this earns no time bonus
+const TIME_BONUS_WINDOW_SECS: f32 = 30.;
+const TIME_BONUS_PER_SEC: f32 = 100.;
+const SURVIVAL_BONUS: u32 = 1000;
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(score_enemy_destroyed)
+            .add_observer(advance_multiplier_on_chain);
+    }
+}
+
+pub fn enemy_points(class: &EnemyClass) -> u32 {
+    match class {
+        EnemyClass::Base => 500,
+        EnemyClass::Shadow => 150,
+        EnemyClass::DefenderOne | EnemyClass::DefenderTwo | EnemyClass::DefenderThree => 100,
+        EnemyClass::Land => 50,
+        EnemyClass::Wall => 0,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LevelScore {
+    pub player_kills: u32,
+    pub chain_kills: u32,
+    pub time_bonus: u32,
+    pub survival_bonus: u32,
+    // Grows by one with every explosion chain wave
+    pub multiplier: u32,
+}
+
+impl Default for LevelScore {
+    fn default() -> Self {
+        Self {
+            player_kills: 0,
+            chain_kills: 0,
+            time_bonus: 0,
+            survival_bonus: 0,
+            multiplier: 1,
+        }
+    }
+}
+
+impl LevelScore {
+    pub fn total(&self) -> u32 {
+        self.player_kills + self.chain_kills + self.time_bonus + self.survival_bonus
+    }
+    pub fn add_kill(&mut self, class: &EnemyClass, source: &EnemyDestructionSource) {
+        let points = enemy_points(class) * self.multiplier;
+        match source {
+            EnemyDestructionSource::Player => self.player_kills += points,
+            EnemyDestructionSource::ExplosionChain => self.chain_kills += points,
+        }
+    }
+    pub fn advance_chain(&mut self) {
+        self.multiplier += 1;
+    }
+    // Nothing is awarded for the time or the chain if the player didn't survive
+    pub fn finalize(&mut self, elapsed_secs: f32, survived: bool) {
+        if survived {
+            self.time_bonus =
+                ((TIME_BONUS_WINDOW_SECS - elapsed_secs).max(0.) * TIME_BONUS_PER_SEC) as u32;
+            self.survival_bonus = SURVIVAL_BONUS * self.multiplier;
+        } else {
+            self.time_bonus = 0;
+            self.survival_bonus = 0;
+        }
+    }
+}
+
+fn score_enemy_destroyed(
+    trigger: Trigger<EnemyDestroyedEvent>,
+    mut level_stats: Single<&mut LevelStats>,
+) {
+    let event = trigger.event();
+    level_stats
+        .score
+        .add_kill(&event.class, &event.destruction_source);
+}
+
+fn advance_multiplier_on_chain(
+    _trigger: Trigger<ExplosionChainEvent>,
+    mut level_stats: Single<&mut LevelStats>,
+) {
+    level_stats.score.advance_chain();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kills_are_worth_more_as_the_chain_grows() {
+        let mut score = LevelScore::default();
+        score.add_kill(&EnemyClass::Base, &EnemyDestructionSource::Player);
+        score.advance_chain();
+        score.add_kill(
+            &EnemyClass::DefenderOne,
+            &EnemyDestructionSource::ExplosionChain,
+        );
+        score.advance_chain();
+        score.add_kill(&EnemyClass::Land, &EnemyDestructionSource::ExplosionChain);
+        assert_eq!(score.player_kills, 500);
+        assert_eq!(score.chain_kills, 100 * 2 + 50 * 3);
+        assert_eq!(score.multiplier, 3);
+    }
+
+    #[test]
+    fn time_bonus_shrinks_over_the_window() {
+        let bonus = |elapsed_secs| {
+            let mut score = LevelScore::default();
+            score.finalize(elapsed_secs, true);
+            score.time_bonus
+        };
+        assert_eq!(bonus(0.), 3000);
+        assert_eq!(bonus(10.), 2000);
+        assert_eq!(bonus(29.5), 50);
+        assert_eq!(bonus(TIME_BONUS_WINDOW_SECS), 0);
+        assert_eq!(bonus(120.), 0);
+    }
+
+    #[test]
+    fn survival_bonus_uses_the_final_multiplier() {
+        let mut score = LevelScore::default();
+        score.advance_chain();
+        score.advance_chain();
+        score.finalize(40., true);
+        assert_eq!(score.survival_bonus, SURVIVAL_BONUS * 3);
+        assert_eq!(score.total(), SURVIVAL_BONUS * 3);
+    }
+
+    #[test]
+    fn nothing_is_awarded_for_losing() {
+        let mut score = LevelScore::default();
+        score.add_kill(&EnemyClass::Base, &EnemyDestructionSource::Player);
+        score.advance_chain();
+        score.finalize(5., true);
+        score.finalize(5., false);
+        assert_eq!(score.time_bonus, 0);
+        assert_eq!(score.survival_bonus, 0);
+        // Kills still count towards the total
+        assert_eq!(score.total(), 500);
+    }
+}
@@ -1,33 +1,57 @@
 use bevy::{ecs::spawn::SpawnWith, prelude::*, render::camera::ScalingMode};
-use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet, NodeVars};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     app_state::AppState,
     gameplay::{
+        camera_effects::CameraShake,
         enemy::{EnemyClass, EnemyCounts, EnemyDestroyedEvent},
         explosion::ExplosionChainEvent,
         game_run::GameRun,
-        level::{LevelConfig, LevelStats},
+        level::{LevelConfig, LevelState, LevelStats},
+        level_theme::{DEFAULT_AMBIENT_LIGHT, LevelTheme, apply_theme, background_bundle},
+        player::{PLAYER_SIZE, Player},
     },
+    loading::SceneHandles,
+    localization::Localized,
+    menu::spawn_lines,
 };
 
 pub const STAGE_WIDTH: f32 = 520.;
 pub const STAGE_HEIGHT: f32 = 720.;
+// How much of the view is kept ahead of the player when following
+const FOLLOW_LOOK_AHEAD: f32 = 0.25;
+const FOLLOW_SMOOTHING: f32 = 6.;
 
 pub struct StagePlugin;
 
 impl Plugin for StagePlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(update_enemy_status_when_chain_triggered)
+        app.init_resource::<StageView>()
+            .add_observer(update_enemy_status_when_chain_triggered)
             .add_observer(update_enemy_count_when_destroyed)
             .add_systems(Startup, setup)
-            .add_systems(Update, update_level_stopwatch_text);
+            .add_systems(OnExit(AppState::Gameplay), reset_stage_view)
+            .add_systems(
+                Update,
+                (
+                    update_level_stopwatch_text,
+                    update_level_score_text,
+                    (scroll_stage, keep_player_in_view)
+                        .chain()
+                        .run_if(in_state(LevelState::Playing)),
+                    move_camera_with_view.run_if(resource_changed::<StageView>),
+                    apply_stage_css_vars,
+                ),
+            );
     }
 }
 
 fn setup(mut commands: Commands, mut ambient_light: ResMut<AmbientLight>) {
-    ambient_light.brightness = 1000.0;
+    ambient_light.brightness = DEFAULT_AMBIENT_LIGHT;
 
+    let home = Transform::from_xyz(0., 0., 100.).looking_at(Vec3::ZERO, Vec3::Y);
     commands.spawn((
         Camera3d::default(),
         Projection::from(OrthographicProjection {
@@ -36,18 +60,190 @@ fn setup(mut commands: Commands, mut ambient_light: ResMut<AmbientLight>) {
             },
             ..OrthographicProjection::default_3d()
         }),
-        Transform::from_xyz(0., 0., 100.).looking_at(Vec3::ZERO, Vec3::Y),
+        home,
+        CameraShake::new(home),
     ));
 }
 
-pub fn spawn_stage(commands: &mut Commands, asset_server: &AssetServer) {
+// How big a level's play area is and how the camera moves over it. The stage is always
+// centred on the origin, the default is one screen so the camera never moves
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StageConfig {
+    pub size: (f32, f32),
+    pub scroll: StageScroll,
+}
+
+impl Default for StageConfig {
+    fn default() -> Self {
+        Self {
+            size: (STAGE_WIDTH, STAGE_HEIGHT),
+            scroll: StageScroll::Follow,
+        }
+    }
+}
+
+impl StageConfig {
+    pub fn tall(height: f32, scroll: StageScroll) -> Self {
+        Self {
+            size: (STAGE_WIDTH, height),
+            scroll,
+        }
+    }
+    pub fn size(&self) -> Vec2 {
+        self.size.into()
+    }
+    pub fn rect(&self) -> Rect {
+        Rect::from_center_size(Vec2::ZERO, self.size())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum StageScroll {
+    // Keeps the player in view
+    #[default]
+    Follow,
+    // Climbs from the bottom of the stage at a steady speed, pushing the player along
+    Auto {
+        speed: f32,
+    },
+}
+
+// The part of the stage on screen. The whole width is always visible and at most a screen's
+// height, taller stages scroll vertically
+#[derive(Resource, Clone, Debug, Default)]
+pub struct StageView {
+    pub stage: StageConfig,
+    pub center: Vec2,
+}
+
+impl StageView {
+    pub fn new(stage: StageConfig, start_position: Vec2) -> Self {
+        let mut view = Self {
+            stage,
+            center: Vec2::ZERO,
+        };
+        let (bottom, top) = view.scroll_range();
+        view.center.y = match stage.scroll {
+            StageScroll::Follow => view.follow_target(start_position.y).clamp(bottom, top),
+            StageScroll::Auto { .. } => bottom,
+        };
+        view
+    }
+    pub fn size(&self) -> Vec2 {
+        let stage = self.stage.size();
+        vec2(stage.x, stage.y.min(STAGE_HEIGHT))
+    }
+    pub fn rect(&self) -> Rect {
+        Rect::from_center_size(self.center, self.size())
+    }
+    // Nearest position where something of the given size is entirely on screen
+    pub fn keep_inside(&self, position: Vec2, size: Vec2) -> Vec2 {
+        let view = self.rect();
+        position.clamp(view.min + size / 2., view.max - size / 2.)
+    }
+    // Moves the view up or down without showing past the stage
+    pub fn scroll_by(&mut self, delta: f32) {
+        let (bottom, top) = self.scroll_range();
+        self.center.y = (self.center.y + delta).clamp(bottom, top);
+    }
+    // Lowest and highest the view's centre can go without showing past the stage
+    fn scroll_range(&self) -> (f32, f32) {
+        let half = ((self.stage.size().y - self.size().y) / 2.).max(0.);
+        (-half, half)
+    }
+    fn follow_target(&self, player_y: f32) -> f32 {
+        player_y + self.size().y * FOLLOW_LOOK_AHEAD
+    }
+    fn set_css_vars(&self, vars: &mut NodeVars) {
+        let size = self.size();
+        // Both are valid lengths so neither can fail to parse
+        let _ = vars.set_var("--stage-width", &format!("{}px", size.x));
+        let _ = vars.set_var("--stage-height", &format!("{}px", size.y));
+    }
+}
+
+fn scroll_stage(
+    time: Res<Time>,
+    mut view: ResMut<StageView>,
+    player: Option<Single<&Transform, With<Player>>>,
+) {
+    let (bottom, top) = view.scroll_range();
+    if bottom == top {
+        return;
+    }
+    let center_y = match view.stage.scroll {
+        StageScroll::Follow => {
+            let Some(player) = player else {
+                return;
+            };
+            let target = view.follow_target(player.translation.y).clamp(bottom, top);
+            let blend = 1. - (-FOLLOW_SMOOTHING * time.delta_secs()).exp();
+            view.center.y + (target - view.center.y) * blend
+        }
+        StageScroll::Auto { speed } => (view.center.y + speed * time.delta_secs()).min(top),
+    };
+    if center_y != view.center.y {
+        view.center.y = center_y;
+    }
+}
+
+// Auto scrolling carries on whether or not the player moves, so they're pushed along with it
+fn keep_player_in_view(view: Res<StageView>, player: Option<Single<&mut Transform, With<Player>>>) {
+    let Some(mut player) = player else {
+        return;
+    };
+    let position = player.translation.truncate();
+    let inside = view.keep_inside(position, PLAYER_SIZE);
+    if inside != position {
+        player.translation = inside.extend(player.translation.z);
+    }
+}
+
+fn move_camera_with_view(view: Res<StageView>, mut camera_q: Query<&mut CameraShake>) {
+    for mut shake in &mut camera_q {
+        shake.set_home_position(view.center);
+    }
+}
+
+fn reset_stage_view(mut commands: Commands) {
+    commands.insert_resource(StageView::default());
+}
+
+// The UI lines up with the stage through these variables, so each root is kept up to date
+fn apply_stage_css_vars(
+    mut commands: Commands,
+    view: Res<StageView>,
+    root_q: Query<(Entity, Ref<NodeStyleSheet>), Without<ChildOf>>,
+) {
+    for (entity, style_sheet) in &root_q {
+        if view.is_changed() || style_sheet.is_added() {
+            // Other variables, like the colour palette's, are set on the same roots
+            let view = view.clone();
+            commands
+                .entity(entity)
+                .entry::<NodeVars>()
+                .or_default()
+                .and_modify(move |mut vars| view.set_css_vars(&mut vars));
+        }
+    }
+}
+
+pub fn spawn_stage(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    scenes: &SceneHandles,
+    stage: &StageConfig,
+    theme: &LevelTheme,
+) {
+    apply_theme(commands, theme);
+    // The background is modelled for a single screen
+    let scale = stage.size() / vec2(STAGE_WIDTH, STAGE_HEIGHT);
     commands.spawn((
         // TODO: specify scope outside of file to reduce coupling
         StateScoped(AppState::Gameplay),
-        SceneRoot(
-            asset_server
-                .load(GltfAssetLabel::Scene(0).from_asset("backgrounds/generic-background.glb")),
-        ),
+        background_bundle(asset_server, scenes, theme),
+        Transform::from_scale(scale.extend(1.)),
     ));
 }
 
@@ -57,7 +253,14 @@ pub fn spawn_level_info_panel(
     level_config: &LevelConfig,
     game_run: &GameRun,
 ) -> impl Bundle {
-    let LevelConfig { name, notes, .. } = level_config;
+    let LevelConfig {
+        name,
+        message_id,
+        notes,
+        goals,
+        ..
+    } = level_config;
+    let goals = goals.description();
     commands.spawn((
         StateScoped(AppState::Gameplay),
         NodeStyleSheet::new(asset_server.load("styles/all.css")),
@@ -67,21 +270,40 @@ pub fn spawn_level_info_panel(
             Node::default(),
             ClassList::new_with_classes(["level-info-panel-content"]),
             children![
-                (Text::new(*notes), ClassList::new_with_classes(["text"])),
                 (
-                    Text::new(format!(
-                        "{:02}/{:02}",
-                        game_run.current_level_index() + 1,
-                        game_run.total_level_count()
-                    )),
+                    Text::default(),
+                    Localized::level_notes(*message_id, notes),
+                    ClassList::new_with_classes(["text"])
+                ),
+                (
+                    Text::default(),
+                    ClassList::new_with_classes(["level-goals"]),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawn_lines(spawner, goals);
+                    })),
+                ),
+                (
+                    Text::new(level_number_text(game_run)),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (
+                    Text::default(),
+                    Localized::level_name(*message_id, name),
                     ClassList::new_with_classes(["heading"])
                 ),
-                (Text::new(*name), ClassList::new_with_classes(["heading"])),
             ],
         )),
     ));
 }
 
+fn level_number_text(game_run: &GameRun) -> String {
+    let level_number = game_run.current_level_index() + 1;
+    match game_run.total_level_count() {
+        Some(total) => format!("{level_number:02}/{total:02}"),
+        None => format!("{level_number:02}"),
+    }
+}
+
 #[derive(Component, Debug)]
 struct StatEnemyClass(EnemyClass);
 
@@ -105,7 +327,7 @@ fn enemy_stat(class: &EnemyClass, count: u32) -> impl Bundle {
                 StatEnemyClass(class),
             ));
             spawner.spawn(Text::new(format!("/{:02}", count)));
-            spawner.spawn(Text::new(class.to_string()));
+            spawner.spawn((Text::default(), Localized::new(class.message_id())));
         })),
     )
 }
@@ -113,6 +335,12 @@ fn enemy_stat(class: &EnemyClass, count: u32) -> impl Bundle {
 #[derive(Component, Debug)]
 struct LevelStopwatchText;
 
+#[derive(Component, Debug)]
+struct LevelScoreText;
+
+#[derive(Component, Debug)]
+struct ScoreMultiplierText;
+
 pub fn spawn_level_stats_panel(
     commands: &mut Commands,
     asset_server: &AssetServer,
@@ -130,6 +358,18 @@ pub fn spawn_level_stats_panel(
                 Text::default(),
                 Children::spawn_one((TextSpan::new(format!("{:.2}", 0.)), LevelStopwatchText)),
             ));
+            spawner.spawn((
+                Text::default(),
+                children![
+                    (TextSpan::new("0"), LevelScoreText),
+                    TextSpan::new(" "),
+                    (
+                        TextSpan::default(),
+                        Localized::new("hud-multiplier").with_arg("multiplier", 1u32),
+                        ScoreMultiplierText
+                    ),
+                ],
+            ));
             spawner
                 .spawn((
                     Node::default(),
@@ -165,6 +405,22 @@ fn update_level_stopwatch_text(
     }
 }
 
+fn update_level_score_text(
+    level_stats: Single<&LevelStats>,
+    mut score_span_q: Query<&mut TextSpan, With<LevelScoreText>>,
+    mut multiplier_q: Query<&mut Localized, With<ScoreMultiplierText>>,
+) {
+    for mut span in &mut score_span_q {
+        **span = level_stats.score.total().to_string();
+    }
+    // Only touched when it changes so the text isn't translated again every frame
+    let multiplier =
+        Localized::new("hud-multiplier").with_arg("multiplier", level_stats.score.multiplier);
+    for mut localized in &mut multiplier_q {
+        localized.set_if_neq(multiplier.clone());
+    }
+}
+
 fn update_enemy_status_when_chain_triggered(
     trigger: Trigger<ExplosionChainEvent>,
     stat_q: Query<(&mut ClassList, &StatEnemyClass), With<EnemyStat>>,
@@ -198,3 +454,42 @@ fn update_enemy_count_when_destroyed(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    #[test]
+    fn idle_player_is_pushed_along_by_auto_scroll() {
+        let stage = StageConfig::tall(STAGE_HEIGHT * 3., StageScroll::Auto { speed: 40. });
+        let start = vec2(0., -STAGE_HEIGHT * 1.5 + PLAYER_SIZE.y);
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .insert_resource(StageView::new(stage, start))
+            .add_systems(Update, (scroll_stage, keep_player_in_view).chain());
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_translation(start.extend(0.))))
+            .id();
+        // Long enough to scroll all the way to the top
+        for _ in 0..600 {
+            app.update();
+            let view = app.world().resource::<StageView>().rect();
+            let position = app.world().get::<Transform>(player).unwrap().translation;
+            assert!(
+                position.y - PLAYER_SIZE.y / 2. >= view.min.y - 0.01,
+                "player at {position} fell below the view starting at {}",
+                view.min.y
+            );
+        }
+        let view = app.world().resource::<StageView>().rect();
+        assert_eq!(view.max.y, stage.rect().max.y);
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{
+    level::LevelState,
+    pool::{EntityPool, Pooled},
+    stage::StageView,
+};
+
+// How far past the edge a body goes before it counts as gone, so it's fully off screen first
+const STAGE_BOUNDS_MARGIN: f32 = 24.;
+
+pub struct StageBoundsPlugin;
+
+impl Plugin for StageBoundsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            check_stage_bounds.run_if(in_state(LevelState::Playing)),
+        );
+    }
+}
+
+// Opt in for anything that moves on its own, like projectiles and pickups
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StageBounds {
+    // Despawned, or released if it came from a pool
+    CleanUp,
+    // Comes back in on the opposite side
+    Wrap,
+}
+
+// Triggered on the body before it is cleaned up or wrapped
+#[derive(Event, Clone, Debug, Reflect)]
+pub struct LeftStageEvent {
+    pub position: Vec2,
+    // Where the body was moved to, None if it was cleaned up
+    pub wrapped_to: Option<Vec2>,
+}
+
+// Wrapping across an axis lands just inside the opposite margin
+fn wrap_axis(value: f32, min: f32, max: f32) -> f32 {
+    if value < min {
+        value + (max - min)
+    } else if value > max {
+        value - (max - min)
+    } else {
+        value
+    }
+}
+
+fn check_stage_bounds(
+    mut commands: Commands,
+    mut pool: ResMut<EntityPool>,
+    view: Res<StageView>,
+    bodies_q: Query<(Entity, &StageBounds, &mut Transform, Option<&Pooled>)>,
+) {
+    // The part on screen, so shots can't hit what's still scrolled out of view
+    let bounds = view.rect().inflate(STAGE_BOUNDS_MARGIN);
+    for (entity, stage_bounds, mut transform, pooled) in bodies_q {
+        let position = transform.translation.truncate();
+        if bounds.contains(position) {
+            continue;
+        }
+        match stage_bounds {
+            StageBounds::CleanUp => {
+                commands.trigger_targets(
+                    LeftStageEvent {
+                        position,
+                        wrapped_to: None,
+                    },
+                    entity,
+                );
+                pool.recycle(&mut commands, entity, pooled);
+            }
+            StageBounds::Wrap => {
+                let wrapped = vec2(
+                    wrap_axis(position.x, bounds.min.x, bounds.max.x),
+                    wrap_axis(position.y, bounds.min.y, bounds.max.y),
+                );
+                transform.translation = wrapped.extend(transform.translation.z);
+                commands.trigger_targets(
+                    LeftStageEvent {
+                        position,
+                        wrapped_to: Some(wrapped),
+                    },
+                    entity,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use bevy::{prelude::*, time::Stopwatch};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        game_run::GameRun,
+        level::{LevelState, LevelStats},
+    },
+    localization::Localized,
+    storage,
+};
+
+const TIME_ATTACK_BEST_KEY: &str = "time_attack_best";
+
+pub struct TimeAttackPlugin;
+
+impl Plugin for TimeAttackPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<TimeAttackBest>(TIME_ATTACK_BEST_KEY))
+            .add_systems(
+                OnEnter(LevelState::Loading),
+                spawn_time_attack_panel.run_if(any_with_component::<TimeAttackClock>),
+            )
+            .add_systems(OnEnter(LevelState::Complete), record_split)
+            .add_systems(
+                FixedUpdate,
+                tick_time_attack_clock.run_if(in_state(AppState::Gameplay)),
+            )
+            .add_systems(
+                Update,
+                update_time_attack_text.run_if(any_with_component::<TimeAttackClock>),
+            );
+    }
+}
+
+// Cumulative time at the end of each level
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TimeAttackRecord {
+    pub splits: Vec<f32>,
+}
+
+impl TimeAttackRecord {
+    pub fn total_secs(&self) -> Option<f32> {
+        self.splits.last().copied()
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TimeAttackBest(pub Option<TimeAttackRecord>);
+
+// Lives on the GameRun entity so it is reset along with the run
+#[derive(Component, Clone, Debug)]
+pub struct TimeAttackClock {
+    stopwatch: Stopwatch,
+    current: TimeAttackRecord,
+    // Snapshot from the start of the run so comparisons don't shift once a new best is saved
+    personal_best: Option<TimeAttackRecord>,
+}
+
+impl TimeAttackClock {
+    pub fn new(time_attack_best: &TimeAttackBest) -> Self {
+        Self {
+            stopwatch: Stopwatch::new(),
+            current: TimeAttackRecord::default(),
+            personal_best: time_attack_best.0.clone(),
+        }
+    }
+    pub fn elapsed_secs(&self) -> f32 {
+        self.stopwatch.elapsed_secs()
+    }
+    pub fn is_new_personal_best(&self) -> bool {
+        match (&self.personal_best, self.current.total_secs()) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(best), Some(total)) => best.total_secs().is_none_or(|best| total < best),
+        }
+    }
+    // One line per finished level with the difference to the personal best split
+    pub fn splits_text(&self) -> String {
+        self.current
+            .splits
+            .iter()
+            .enumerate()
+            .map(|(index, split)| {
+                let best_split = self
+                    .personal_best
+                    .as_ref()
+                    .and_then(|best| best.splits.get(index));
+                match best_split {
+                    Some(best_split) => {
+                        format!("{:02} {split:.2} ({:+.2})", index + 1, split - best_split)
+                    }
+                    None => format!("{:02} {split:.2}", index + 1),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+    pub fn summary(&self) -> Vec<Localized> {
+        let mut summary = vec![
+            Localized::new("time-attack-summary")
+                .with_arg("secs", format!("{:.2}", self.elapsed_secs())),
+        ];
+        if self.is_new_personal_best() {
+            summary.push(Localized::new("time-attack-new-best"));
+        } else if let Some(best) = self
+            .personal_best
+            .as_ref()
+            .and_then(TimeAttackRecord::total_secs)
+        {
+            summary.push(Localized::new("time-attack-best").with_arg("secs", format!("{best:.2}")));
+        }
+        summary
+    }
+}
+
+fn tick_time_attack_clock(time: Res<Time>, clock_q: Query<&mut TimeAttackClock>) {
+    for mut clock in clock_q {
+        clock.stopwatch.tick(time.delta());
+    }
+}
+
+fn record_split(
+    mut time_attack_best: ResMut<TimeAttackBest>,
+    level_stats: Single<&LevelStats>,
+    game_run: Single<(&GameRun, &mut TimeAttackClock)>,
+) {
+    let (game_run, mut clock) = game_run.into_inner();
+    if level_stats.success != Some(true) {
+        return;
+    }
+    let split = clock.elapsed_secs();
+    clock.current.splits.push(split);
+    if !game_run.has_more_levels() {
+        clock.stopwatch.pause();
+        if clock.is_new_personal_best() {
+            time_attack_best.0 = Some(clock.current.clone());
+            storage::save(TIME_ATTACK_BEST_KEY, &*time_attack_best);
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+struct TimeAttackTimeText;
+
+#[derive(Component, Debug)]
+struct TimeAttackSplitsText;
+
+fn spawn_time_attack_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        StateScoped(AppState::Gameplay),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        ClassList::new_with_classes(["time-attack-panel"]),
+        children![
+            (
+                Text::default(),
+                Localized::new("time-attack-heading"),
+                ClassList::new_with_classes(["text"])
+            ),
+            (
+                Text::default(),
+                TimeAttackTimeText,
+                ClassList::new_with_classes(["time-attack-time"])
+            ),
+            (
+                Text::default(),
+                TimeAttackSplitsText,
+                ClassList::new_with_classes(["time-attack-splits"])
+            ),
+        ],
+    ));
+}
+
+fn update_time_attack_text(
+    clock: Single<&TimeAttackClock>,
+    mut time_q: Query<&mut Text, (With<TimeAttackTimeText>, Without<TimeAttackSplitsText>)>,
+    mut splits_q: Query<&mut Text, With<TimeAttackSplitsText>>,
+) {
+    for mut text in &mut time_q {
+        text.0 = format!("{:.2}", clock.elapsed_secs());
+    }
+    for mut text in &mut splits_q {
+        let splits = clock.splits_text();
+        if text.0 != splits {
+            text.0 = splits;
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::enemy::{Enemy, EnemyClass},
+    settings::Settings,
+};
+
+// Above the defender model so the marker is never hidden
+const MARKER_Z: f32 = 20.;
+const MARKER_SIZE: f32 = 12.;
+// A dark edge keeps the marker readable against any defender colour
+const MARKER_OUTLINE_SIZE: f32 = 16.;
+
+pub struct WaveMarkerPlugin;
+
+impl Plugin for WaveMarkerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveMarkerAssets>().add_systems(
+            Update,
+            (
+                recolor_wave_markers.run_if(resource_changed::<Settings>),
+                add_wave_markers,
+            ),
+        );
+    }
+}
+
+// A different shape for each wave, so they can be told apart without colour
+fn marker_meshes() -> [(EnemyClass, Mesh); 3] {
+    [
+        (EnemyClass::DefenderOne, Circle::new(0.5).into()),
+        (EnemyClass::DefenderTwo, RegularPolygon::new(0.6, 3).into()),
+        (EnemyClass::DefenderThree, Rhombus::new(1., 1.).into()),
+    ]
+}
+
+#[derive(Resource, Debug)]
+struct WaveMarkerAssets {
+    markers: Vec<(EnemyClass, Handle<Mesh>, Handle<StandardMaterial>)>,
+    outline: Handle<StandardMaterial>,
+}
+
+impl WaveMarkerAssets {
+    fn get(&self, class: EnemyClass) -> Option<(Handle<Mesh>, Handle<StandardMaterial>)> {
+        self.markers
+            .iter()
+            .find(|(marked, _, _)| *marked == class)
+            .map(|(_, mesh, material)| (mesh.clone(), material.clone()))
+    }
+}
+
+fn marker_material(color: Color) -> StandardMaterial {
+    StandardMaterial {
+        base_color: color,
+        unlit: true,
+        ..default()
+    }
+}
+
+impl FromWorld for WaveMarkerAssets {
+    fn from_world(world: &mut World) -> Self {
+        let meshes = marker_meshes()
+            .map(|(class, mesh)| (class, world.resource_mut::<Assets<Mesh>>().add(mesh)));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let outline = materials.add(marker_material(Color::BLACK));
+        let markers = meshes
+            .into_iter()
+            // Coloured properly once the settings are seen
+            .map(|(class, mesh)| (class, mesh, materials.add(marker_material(Color::WHITE))))
+            .collect();
+        Self { markers, outline }
+    }
+}
+
+// Kept out of the defender's own tint
+#[derive(Component, Debug)]
+pub struct WaveMarker;
+
+fn recolor_wave_markers(
+    settings: Res<Settings>,
+    assets: Res<WaveMarkerAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (class, _, material) in &assets.markers {
+        let Some(color) = settings.color_palette.wave(*class) else {
+            continue;
+        };
+        if let Some(material) = materials.get_mut(material) {
+            material.base_color = color;
+        }
+    }
+}
+
+fn add_wave_markers(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    assets: Res<WaveMarkerAssets>,
+    enemy_q: Query<(Entity, &EnemyClass, &Transform), Added<Enemy>>,
+) {
+    if !settings.wave_markers {
+        return;
+    }
+    for (entity, class, transform) in &enemy_q {
+        let Some((mesh, material)) = assets.get(*class) else {
+            continue;
+        };
+        // Cancels out the enemy's own scale so every marker is the same size
+        let scale = MARKER_SIZE / transform.scale.truncate();
+        let outline_scale = MARKER_OUTLINE_SIZE / transform.scale.truncate();
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                Name::new("Wave Marker Outline"),
+                WaveMarker,
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(assets.outline.clone()),
+                Transform::from_xyz(0., 0., MARKER_Z - 1.).with_scale(outline_scale.extend(1.)),
+            ));
+            parent.spawn((
+                Name::new("Wave Marker"),
+                WaveMarker,
+                Mesh3d(mesh),
+                MeshMaterial3d(material),
+                Transform::from_xyz(0., 0., MARKER_Z).with_scale(scale.extend(1.)),
+            ));
+        });
+    }
+}
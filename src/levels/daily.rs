@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::level::LevelConfig,
+    levels::endless::{EnemyPalette, LevelGenerator},
+    loading::SceneHandles,
+};
+
+// Sets the par time and theme, the enemies come from the day's palette
+const DAILY_LEVEL_NUMBER: u32 = 8;
+
+const NOTES: &str = concat!(
+    "A new level every day, the same for everyone on the same date.\n",
+    "\n",
+    "Only your first attempt counts - make it a good one!"
+);
+
+pub fn get_config(scenes: &SceneHandles, seed: u64) -> LevelConfig {
+    LevelGenerator::new(seed, DAILY_LEVEL_NUMBER)
+        .with_palette(EnemyPalette::from_seed(seed))
+        .generate(
+            scenes,
+            "Daily Challenge",
+            "level-name-daily-challenge",
+            NOTES,
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::scene::ScenePlugin;
+
+    use super::*;
+    use crate::gameplay::{daily_challenge::seed_for_date, enemy::EnemyClass};
+
+    #[test]
+    fn enemies_come_from_the_days_palette() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScenePlugin));
+        let scenes = SceneHandles::new(app.world().resource::<AssetServer>());
+        for date in ["2025-01-01", "2025-01-02", "2025-06-30", "2028-02-29"] {
+            let seed = seed_for_date(date);
+            let palette = EnemyPalette::from_seed(seed);
+            assert_eq!(palette, EnemyPalette::from_seed(seed));
+            let config = get_config(&scenes, seed);
+            let count = |class: EnemyClass| {
+                config
+                    .enemies
+                    .iter()
+                    .filter(|enemy| enemy.class() == class)
+                    .count() as u32
+            };
+            for class in [
+                EnemyClass::DefenderOne,
+                EnemyClass::DefenderTwo,
+                EnemyClass::DefenderThree,
+            ] {
+                let wanted = palette
+                    .defenders
+                    .iter()
+                    .find(|(palette_class, _)| *palette_class == class)
+                    .map_or(0, |(_, count)| *count);
+                assert!(count(class) <= wanted, "{date}: too many {class}");
+            }
+            // An empty level would pass the checks above, so the palette has to show up in it
+            assert!(
+                palette
+                    .defenders
+                    .iter()
+                    .any(|&(class, wanted)| wanted > 0 && count(class) > 0),
+                "{date}: none of {:?} were placed",
+                palette.defenders
+            );
+            assert!(count(EnemyClass::Shadow) <= palette.shadows, "{date}");
+            assert!(count(EnemyClass::Land) <= palette.land, "{date}");
+            assert!(count(EnemyClass::Wall) <= palette.walls, "{date}");
+            assert_eq!(count(EnemyClass::Base), 1, "{date}");
+        }
+    }
+}
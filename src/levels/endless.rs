@@ -0,0 +1,405 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::{
+        enemy::{ENEMY_BASE_SIZE, ENEMY_DEFENDER_SIZE, ENEMY_SHADOW_SIZE, EnemyBundle, EnemyClass},
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::LevelGoals,
+        stage::{STAGE_HEIGHT, STAGE_WIDTH, StageConfig},
+    },
+    loading::SceneHandles,
+};
+
+// Every generated level keeps a clear row along the bottom of the stage and a clear firing lane
+// straight up to the base. Staying inside them and shooting the base always survives the chain:
+// nothing can block the shots and no explosion grows far enough to reach the player.
+const BASE_Y: f32 = STAGE_HEIGHT / 2. - 30.;
+const START_Y: f32 = -STAGE_HEIGHT / 2. + 20.;
+const SAFE_ROW_TOP: f32 = -STAGE_HEIGHT / 2. + 60.;
+const FIRING_LANE_HALF_WIDTH: f32 = 28.;
+// Explosions grow 40 pixels past their source on each side, plus some room to spare
+const EXPLOSION_MARGIN: f32 = 48.;
+const PIECE_GAP: f32 = 8.;
+const PLACEMENT_ATTEMPTS: usize = 40;
+const PLAYER_SPEED: f32 = 200.;
+
+const NOTES: &str = concat!(
+    "Levels keep coming until you lose one.\n",
+    "\n",
+    "Every level is tougher than the last, but there is always a way through."
+);
+
+pub fn get_config(scenes: &SceneHandles, seed: u64, level_number: u32) -> LevelConfig {
+    LevelGenerator::new(seed, level_number).generate(scenes, "Endless", "level-name-endless", NOTES)
+}
+
+// SplitMix64 so a seed produces the same levels on every platform without an extra dependency
+#[derive(Clone, Debug)]
+pub struct LevelRng(u64);
+
+impl LevelRng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        let unit = (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32;
+        min + unit * (max - min)
+    }
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.range(0., 1.) < probability
+    }
+}
+
+// How many of each enemy a level asks for, used instead of the level number's random mix
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnemyPalette {
+    // Waves in order, so there's never a second wave without a first
+    pub defenders: Vec<(EnemyClass, u32)>,
+    pub shadows: u32,
+    pub land: u32,
+    pub walls: u32,
+}
+
+impl EnemyPalette {
+    // Drawn from its own generator so the palette doesn't depend on where pieces end up
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = LevelRng::new(seed);
+        let mut pick = |min: u64, max: u64| (min + rng.next_u64() % (max - min + 1)) as u32;
+        let waves = pick(1, 3) as usize;
+        let defenders = [
+            EnemyClass::DefenderOne,
+            EnemyClass::DefenderTwo,
+            EnemyClass::DefenderThree,
+        ]
+        .into_iter()
+        .take(waves)
+        .map(|class| (class, pick(3, 6)))
+        .collect();
+        Self {
+            defenders,
+            shadows: pick(0, 8),
+            land: pick(0, 2),
+            walls: pick(0, 4),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Piece {
+    Defender,
+    Shadow,
+    Land,
+    Wall,
+}
+
+pub struct LevelGenerator {
+    rng: LevelRng,
+    level_number: u32,
+    base_x: f32,
+    start_x: f32,
+    palette: Option<EnemyPalette>,
+    // Taken by defenders, shadows and walls so they don't overlap each other
+    occupied: Vec<Rect>,
+}
+
+impl LevelGenerator {
+    pub fn new(seed: u64, level_number: u32) -> Self {
+        let mut rng = LevelRng::new(seed ^ u64::from(level_number).wrapping_mul(0x2545_f491));
+        let base_limit = STAGE_WIDTH / 2. - ENEMY_BASE_SIZE.x;
+        let base_x = rng.range(-base_limit, base_limit);
+        let start_limit = STAGE_WIDTH / 2. - 40.;
+        let start_x = rng.range(-start_limit, start_limit);
+        Self {
+            rng,
+            level_number,
+            base_x,
+            start_x,
+            palette: None,
+            occupied: vec![Rect::from_center_size(
+                vec2(base_x, BASE_Y),
+                ENEMY_BASE_SIZE,
+            )],
+        }
+    }
+    pub fn with_palette(mut self, palette: EnemyPalette) -> Self {
+        self.palette = Some(palette);
+        self
+    }
+    pub fn generate(
+        mut self,
+        scenes: &SceneHandles,
+        name: &str,
+        message_id: &'static str,
+        notes: &str,
+    ) -> LevelConfig {
+        let level = self.level_number.max(1);
+        let palette = self.palette.take();
+        let mut enemies = vec![EnemyBundle::new_base(scenes, vec2(self.base_x, BASE_Y))];
+        let land_count = match &palette {
+            Some(palette) => palette.land,
+            None if level >= 4 => 1 + (level >= 8) as u32,
+            None => 0,
+        };
+        for _ in 0..land_count {
+            if let Some((position, size)) = self.place(Piece::Land) {
+                enemies.push(EnemyBundle::new_land(scenes, position, size));
+            }
+        }
+        let wall_count = palette
+            .as_ref()
+            .map_or((level / 2).min(6), |palette| palette.walls);
+        for _ in 0..wall_count {
+            if let Some((position, size)) = self.place(Piece::Wall) {
+                enemies.push(EnemyBundle::new_wall(scenes, position, size));
+            }
+        }
+        match &palette {
+            Some(palette) => {
+                for &(class, count) in &palette.defenders {
+                    for _ in 0..count {
+                        if let Some((position, _)) = self.place(Piece::Defender) {
+                            enemies.push(EnemyBundle::new_of_class(
+                                scenes,
+                                class,
+                                position,
+                                Vec2::ONE,
+                            ));
+                        }
+                    }
+                }
+            }
+            None => {
+                for _ in 0..(2 + level).min(24) {
+                    if let Some((position, _)) = self.place(Piece::Defender) {
+                        let defender = if level >= 6 && self.rng.chance(0.3) {
+                            EnemyBundle::new_tertiary_defender(scenes, position)
+                        } else if level >= 3 && self.rng.chance(0.4) {
+                            EnemyBundle::new_secondary_defender(scenes, position)
+                        } else {
+                            EnemyBundle::new_primary_defender(scenes, position)
+                        };
+                        enemies.push(defender);
+                    }
+                }
+            }
+        }
+        let shadow_count = palette
+            .as_ref()
+            .map_or(((level - 1) * 2).min(20), |palette| palette.shadows);
+        for _ in 0..shadow_count {
+            if let Some((position, _)) = self.place(Piece::Shadow) {
+                enemies.push(EnemyBundle::new_shadow(scenes, position));
+            }
+        }
+        LevelConfig {
+            name: name.to_string(),
+            message_id: Some(message_id),
+            notes: notes.to_string(),
+            start_position: vec2(self.start_x, START_Y),
+            stage: StageConfig::default(),
+            // Each level looks different from the one before
+            theme: LevelTheme::palette(Palette::all()[level as usize % Palette::all().len()]),
+            goals: LevelGoals::par_time(self.par_time()),
+            enemies,
+        }
+    }
+    // Time to reach the firing lane and for the shots to travel up to the base, with some slack
+    fn par_time(&self) -> f32 {
+        let travel = (self.base_x - self.start_x).abs() / PLAYER_SPEED;
+        let shots = (BASE_Y - START_Y) / PLAYER_SPEED;
+        (travel + shots + 2.).ceil()
+    }
+    fn random_size(&mut self, piece: Piece) -> Vec2 {
+        match piece {
+            Piece::Defender => ENEMY_DEFENDER_SIZE,
+            Piece::Shadow => ENEMY_SHADOW_SIZE,
+            Piece::Land => vec2(self.rng.range(80., 200.), self.rng.range(80., 260.)),
+            Piece::Wall => {
+                let length = self.rng.range(60., 200.);
+                if self.rng.chance(0.5) {
+                    vec2(length, 30.)
+                } else {
+                    vec2(30., length)
+                }
+            }
+        }
+    }
+    fn place(&mut self, piece: Piece) -> Option<(Vec2, Vec2)> {
+        for _ in 0..PLACEMENT_ATTEMPTS {
+            let size = self.random_size(piece);
+            let half = size / 2.;
+            let position = vec2(
+                self.rng
+                    .range(-STAGE_WIDTH / 2. + half.x, STAGE_WIDTH / 2. - half.x),
+                self.rng
+                    .range(-STAGE_HEIGHT / 2. + half.y, STAGE_HEIGHT / 2. - half.y),
+            );
+            let rect = Rect::from_center_size(position, size);
+            if self.is_safe(piece, rect) {
+                if !matches!(piece, Piece::Land) {
+                    self.occupied.push(rect);
+                }
+                return Some((position, size));
+            }
+        }
+        None
+    }
+    fn is_safe(&self, piece: Piece, rect: Rect) -> bool {
+        let overlaps = |a: Rect, b: Rect| !a.intersect(b).is_empty();
+        let safe_row = Rect::new(
+            -STAGE_WIDTH / 2.,
+            -STAGE_HEIGHT / 2.,
+            STAGE_WIDTH / 2.,
+            SAFE_ROW_TOP,
+        );
+        let firing_lane = Rect::new(
+            self.base_x - FIRING_LANE_HALF_WIDTH,
+            -STAGE_HEIGHT / 2.,
+            self.base_x + FIRING_LANE_HALF_WIDTH,
+            BASE_Y,
+        );
+        if overlaps(rect.inflate(EXPLOSION_MARGIN), safe_row) {
+            return false;
+        }
+        // Shots pass over shadows and land, so only their explosions matter and those are already
+        // clear of the row the player waits in
+        let blocks_shots = matches!(piece, Piece::Defender | Piece::Wall);
+        if blocks_shots && overlaps(rect.inflate(PIECE_GAP), firing_lane) {
+            return false;
+        }
+        // Land sits underneath everything else
+        matches!(piece, Piece::Land)
+            || !self
+                .occupied
+                .iter()
+                .any(|occupied| overlaps(rect.inflate(PIECE_GAP), *occupied))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::scene::ScenePlugin;
+
+    use super::*;
+    use crate::gameplay::{enemy::EnemyClass, explosion::final_explosion_size};
+
+    const SEEDS: [u64; 5] = [0, 1, 42, 20_250_101, u64::MAX];
+
+    fn scenes() -> SceneHandles {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScenePlugin));
+        SceneHandles::new(app.world().resource::<AssetServer>())
+    }
+
+    fn overlaps(a: Rect, b: Rect) -> bool {
+        !a.intersect(b).is_empty()
+    }
+
+    #[test]
+    fn same_seed_generates_the_same_level() {
+        let scenes = scenes();
+        for seed in SEEDS {
+            let first = get_config(&scenes, seed, 7);
+            let second = get_config(&scenes, seed, 7);
+            assert_eq!(first.start_position, second.start_position);
+            let layout = |config: &LevelConfig| {
+                config
+                    .enemies
+                    .iter()
+                    .map(|enemy| (enemy.class(), enemy.position(), enemy.scale()))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(layout(&first), layout(&second), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn explosions_never_reach_the_safe_row() {
+        let scenes = scenes();
+        let safe_row = Rect::new(
+            -STAGE_WIDTH / 2.,
+            -STAGE_HEIGHT / 2.,
+            STAGE_WIDTH / 2.,
+            SAFE_ROW_TOP,
+        );
+        for seed in SEEDS {
+            for level_number in 1..=12 {
+                let config = get_config(&scenes, seed, level_number);
+                assert!(
+                    safe_row.contains(config.start_position),
+                    "seed {seed} level {level_number} starts outside the safe row"
+                );
+                for enemy in &config.enemies {
+                    if enemy.class() == EnemyClass::Wall {
+                        assert!(
+                            !overlaps(
+                                Rect::from_center_size(enemy.position(), enemy.size()),
+                                safe_row
+                            ),
+                            "seed {seed} level {level_number} has a wall in the safe row"
+                        );
+                        continue;
+                    }
+                    let reach = Rect::from_center_size(
+                        enemy.position(),
+                        final_explosion_size(enemy.class(), enemy.scale()),
+                    );
+                    assert!(
+                        !overlaps(reach, safe_row),
+                        "seed {seed} level {level_number}: {} at {} reaches the safe row",
+                        enemy.class(),
+                        enemy.position()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn firing_lane_to_the_base_is_clear() {
+        let scenes = scenes();
+        for seed in SEEDS {
+            for level_number in 1..=12 {
+                let config = get_config(&scenes, seed, level_number);
+                let bases = config
+                    .enemies
+                    .iter()
+                    .filter(|enemy| enemy.class() == EnemyClass::Base)
+                    .collect::<Vec<_>>();
+                assert_eq!(bases.len(), 1, "seed {seed} level {level_number}");
+                let base = bases[0].position();
+                let firing_lane = Rect::new(
+                    base.x - FIRING_LANE_HALF_WIDTH,
+                    -STAGE_HEIGHT / 2.,
+                    base.x + FIRING_LANE_HALF_WIDTH,
+                    base.y,
+                );
+                for enemy in &config.enemies {
+                    // Shots pass over shadows and land
+                    let blocks_shots = matches!(
+                        enemy.class(),
+                        EnemyClass::DefenderOne
+                            | EnemyClass::DefenderTwo
+                            | EnemyClass::DefenderThree
+                            | EnemyClass::Wall
+                    );
+                    let rect = Rect::from_center_size(enemy.position(), enemy.size());
+                    assert!(
+                        !blocks_shots || !overlaps(rect, firing_lane),
+                        "seed {seed} level {level_number}: {} at {} blocks the firing lane",
+                        enemy.class(),
+                        enemy.position()
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,17 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{
-    enemy::{DefenderClass, EnemyBundle},
-    level::LevelConfig,
+use crate::{
+    gameplay::{
+        enemy::{DefenderClass, EnemyBundle},
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::{LevelGoal, LevelGoals},
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
 };
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     let square_offsets = &[
         vec2(-15., 15.),
         vec2(15., 15.),
@@ -22,76 +28,80 @@ pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
         vec2(0., -30. * 7.),
     ];
 
-    let mut enemies = vec![EnemyBundle::new_base(asset_server, vec2(0., 330.))];
+    let mut enemies = vec![EnemyBundle::new_base(scenes, vec2(0., 330.))];
 
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(-105., 245.),
         DefenderClass::One,
         line_offsets,
     ));
 
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(-60., 200.),
         DefenderClass::One,
         square_offsets,
     ));
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(0., 200.),
         DefenderClass::Two,
         square_offsets,
     ));
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(60., 200.),
         DefenderClass::Three,
         square_offsets,
     ));
 
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(-60., 50.),
         DefenderClass::One,
         square_offsets,
     ));
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(0., 50.),
         DefenderClass::Two,
         square_offsets,
     ));
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(60., 50.),
         DefenderClass::Three,
         square_offsets,
     ));
 
     enemies.extend(group(
-        asset_server,
+        scenes,
         vec2(105., 245.),
         DefenderClass::Three,
         line_offsets,
     ));
 
     LevelConfig {
-        name: "Choose Your Destiny",
-        notes: "",
+        name: "Choose Your Destiny".into(),
+        message_id: Some("level-name-choose-your-destiny"),
+        notes: "".into(),
         start_position: vec2(0., 100.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::palette(Palette::Toxic),
+        goals: LevelGoals::new(10., LevelGoal::ChainMultiplier(6)),
         enemies,
     }
 }
 
 fn group(
-    asset_server: &AssetServer,
+    scenes: &SceneHandles,
     pos: Vec2,
     class: DefenderClass,
     offsets: &[Vec2],
 ) -> Vec<EnemyBundle> {
     offsets
         .iter()
-        .map(|offset| EnemyBundle::new_defender(asset_server, pos + offset, &class))
+        .map(|offset| EnemyBundle::new_defender(scenes, pos + offset, &class))
         .collect()
 }
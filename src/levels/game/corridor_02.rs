@@ -1,26 +1,31 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{
-    enemy::{DefenderClass, ENEMY_DEFENDER_SIZE, EnemyBundle},
-    level::LevelConfig,
-    stage::{STAGE_HEIGHT, STAGE_WIDTH},
+use crate::{
+    gameplay::{
+        enemy::{DefenderClass, ENEMY_DEFENDER_SIZE, EnemyBundle},
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::{LevelGoal, LevelGoals},
+        stage::{STAGE_HEIGHT, STAGE_WIDTH, StageConfig},
+    },
+    loading::SceneHandles,
 };
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     let hh = 260.;
     let hw = 30.;
     let wall_height = STAGE_HEIGHT / 2. - hh;
 
     let mut enemies = vec![
-        EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-        EnemyBundle::new_shadow(asset_server, vec2(0., 100.)),
+        EnemyBundle::new_base(scenes, vec2(0., 330.)),
+        EnemyBundle::new_shadow(scenes, vec2(0., 100.)),
         EnemyBundle::new_wall(
-            asset_server,
+            scenes,
             vec2(0., -hh - wall_height / 2.),
             vec2(STAGE_WIDTH, wall_height),
         ),
         EnemyBundle::new_land(
-            asset_server,
+            scenes,
             vec2(0., -hh + 30. * 2. / 2.),
             vec2(STAGE_WIDTH, 30. * 2.),
         ),
@@ -35,16 +40,20 @@ pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
     let mut y = hh;
     for (class, count) in counts {
         for _ in 0..count {
-            enemies.push(EnemyBundle::new_defender(asset_server, vec2(-x, y), class));
-            enemies.push(EnemyBundle::new_defender(asset_server, vec2(x, y), class));
+            enemies.push(EnemyBundle::new_defender(scenes, vec2(-x, y), class));
+            enemies.push(EnemyBundle::new_defender(scenes, vec2(x, y), class));
             y -= 30.;
         }
     }
 
     LevelConfig {
-        name: "Corridor Shooter 2",
-        notes: "",
+        name: "Corridor Shooter 2".into(),
+        message_id: Some("level-name-corridor-shooter-2"),
+        notes: "".into(),
         start_position: vec2(0., -hh + 20.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::palette(Palette::Frost),
+        goals: LevelGoals::new(14., LevelGoal::ChainMultiplier(7)),
         enemies,
     }
 }
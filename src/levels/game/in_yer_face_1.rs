@@ -1,15 +1,28 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::LevelGoals,
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "In Yer Face",
-        notes: "",
+        name: "In Yer Face".into(),
+        message_id: Some("level-name-in-yer-face"),
+        notes: "".into(),
         start_position: vec2(0., 280.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::palette(Palette::Ember),
+        goals: LevelGoals::par_time(4.),
         enemies: vec![
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_wall(asset_server, vec2(0., 230.), vec2(200., 40.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_wall(scenes, vec2(0., 230.), vec2(200., 40.)),
         ],
     }
 }
@@ -1,17 +1,30 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::{LevelGoal, LevelGoals},
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "In Yer Face 2",
-        notes: "",
+        name: "In Yer Face 2".into(),
+        message_id: Some("level-name-in-yer-face-2"),
+        notes: "".into(),
         start_position: vec2(0., 280.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::palette(Palette::Ember),
+        goals: LevelGoals::new(5., LevelGoal::PlayerDestroysDefenders),
         enemies: vec![
-            EnemyBundle::new_primary_defender(asset_server, vec2(-160., 280.)),
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_wall(asset_server, vec2(0., 230.), vec2(520., 40.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(160., 280.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-160., 280.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_wall(scenes, vec2(0., 230.), vec2(520., 40.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(160., 280.)),
         ],
     }
 }
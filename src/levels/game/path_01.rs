@@ -1,31 +1,44 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::LevelGoals,
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Off The Beaten Path",
-        notes: "",
+        name: "Off The Beaten Path".into(),
+        message_id: Some("level-name-off-the-beaten-path"),
+        notes: "".into(),
         start_position: vec2(0., -340.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::palette(Palette::Dusk),
+        goals: LevelGoals::par_time(7.),
         enemies: vec![
-            EnemyBundle::new_wall(asset_server, vec2(-200., 255.), vec2(30., 220.)),
-            EnemyBundle::new_wall(asset_server, vec2(200., 255.), vec2(30., 220.)),
-            EnemyBundle::new_wall(asset_server, vec2(-200., -225.), vec2(30., 270.)),
-            EnemyBundle::new_wall(asset_server, vec2(200., -225.), vec2(30., 270.)),
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-240., 120.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-200., 120.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-160., 120.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-240., -60.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-200., -60.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-160., -60.)),
-            EnemyBundle::new_land(asset_server, vec2(0., 0.), vec2(260., 720.)),
-            EnemyBundle::new_shadow(asset_server, vec2(160., 120.)),
-            EnemyBundle::new_shadow(asset_server, vec2(200., 120.)),
-            EnemyBundle::new_shadow(asset_server, vec2(240., 120.)),
-            EnemyBundle::new_shadow(asset_server, vec2(160., -60.)),
-            EnemyBundle::new_shadow(asset_server, vec2(200., -60.)),
-            EnemyBundle::new_shadow(asset_server, vec2(240., -60.)),
+            EnemyBundle::new_wall(scenes, vec2(-200., 255.), vec2(30., 220.)),
+            EnemyBundle::new_wall(scenes, vec2(200., 255.), vec2(30., 220.)),
+            EnemyBundle::new_wall(scenes, vec2(-200., -225.), vec2(30., 270.)),
+            EnemyBundle::new_wall(scenes, vec2(200., -225.), vec2(30., 270.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_shadow(scenes, vec2(-240., 120.)),
+            EnemyBundle::new_shadow(scenes, vec2(-200., 120.)),
+            EnemyBundle::new_shadow(scenes, vec2(-160., 120.)),
+            EnemyBundle::new_shadow(scenes, vec2(-240., -60.)),
+            EnemyBundle::new_shadow(scenes, vec2(-200., -60.)),
+            EnemyBundle::new_shadow(scenes, vec2(-160., -60.)),
+            EnemyBundle::new_land(scenes, vec2(0., 0.), vec2(260., 720.)),
+            EnemyBundle::new_shadow(scenes, vec2(160., 120.)),
+            EnemyBundle::new_shadow(scenes, vec2(200., 120.)),
+            EnemyBundle::new_shadow(scenes, vec2(240., 120.)),
+            EnemyBundle::new_shadow(scenes, vec2(160., -60.)),
+            EnemyBundle::new_shadow(scenes, vec2(200., -60.)),
+            EnemyBundle::new_shadow(scenes, vec2(240., -60.)),
         ],
     }
 }
@@ -1,33 +1,46 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::{LevelGoal, LevelGoals},
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Off The Beaten Path 2",
-        notes: "",
+        name: "Off The Beaten Path 2".into(),
+        message_id: Some("level-name-off-the-beaten-path-2"),
+        notes: "".into(),
         start_position: vec2(0., -340.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::palette(Palette::Dusk),
+        goals: LevelGoals::new(8., LevelGoal::PlayerDestroysDefenders),
         enemies: vec![
-            EnemyBundle::new_primary_defender(asset_server, vec2(-243., 345.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(243., 345.)),
-            EnemyBundle::new_wall(asset_server, vec2(-200., 245.), vec2(30., 260.)),
-            EnemyBundle::new_wall(asset_server, vec2(200., 245.), vec2(30., 260.)),
-            EnemyBundle::new_wall(asset_server, vec2(-200., -210.), vec2(30., 310.)),
-            EnemyBundle::new_wall(asset_server, vec2(200., -210.), vec2(30., 310.)),
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-240., 90.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-200., 90.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-160., 90.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-240., -30.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-200., -30.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-160., -30.)),
-            EnemyBundle::new_land(asset_server, vec2(0., 0.), vec2(260., 720.)),
-            EnemyBundle::new_shadow(asset_server, vec2(160., 90.)),
-            EnemyBundle::new_shadow(asset_server, vec2(200., 90.)),
-            EnemyBundle::new_shadow(asset_server, vec2(240., 90.)),
-            EnemyBundle::new_shadow(asset_server, vec2(160., -30.)),
-            EnemyBundle::new_shadow(asset_server, vec2(200., -30.)),
-            EnemyBundle::new_shadow(asset_server, vec2(240., -30.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-243., 345.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(243., 345.)),
+            EnemyBundle::new_wall(scenes, vec2(-200., 245.), vec2(30., 260.)),
+            EnemyBundle::new_wall(scenes, vec2(200., 245.), vec2(30., 260.)),
+            EnemyBundle::new_wall(scenes, vec2(-200., -210.), vec2(30., 310.)),
+            EnemyBundle::new_wall(scenes, vec2(200., -210.), vec2(30., 310.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_shadow(scenes, vec2(-240., 90.)),
+            EnemyBundle::new_shadow(scenes, vec2(-200., 90.)),
+            EnemyBundle::new_shadow(scenes, vec2(-160., 90.)),
+            EnemyBundle::new_shadow(scenes, vec2(-240., -30.)),
+            EnemyBundle::new_shadow(scenes, vec2(-200., -30.)),
+            EnemyBundle::new_shadow(scenes, vec2(-160., -30.)),
+            EnemyBundle::new_land(scenes, vec2(0., 0.), vec2(260., 720.)),
+            EnemyBundle::new_shadow(scenes, vec2(160., 90.)),
+            EnemyBundle::new_shadow(scenes, vec2(200., 90.)),
+            EnemyBundle::new_shadow(scenes, vec2(240., 90.)),
+            EnemyBundle::new_shadow(scenes, vec2(160., -30.)),
+            EnemyBundle::new_shadow(scenes, vec2(200., -30.)),
+            EnemyBundle::new_shadow(scenes, vec2(240., -30.)),
         ],
     }
 }
@@ -1,31 +1,44 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::{LevelTheme, Palette},
+        rating::{LevelGoal, LevelGoals},
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Off The Beaten Path 3",
-        notes: "",
+        name: "Off The Beaten Path 3".into(),
+        message_id: Some("level-name-off-the-beaten-path-3"),
+        notes: "".into(),
         start_position: vec2(0., -340.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::palette(Palette::Dusk),
+        goals: LevelGoals::new(10., LevelGoal::PlayerDestroysDefenders),
         enemies: vec![
-            EnemyBundle::new_wall(asset_server, vec2(-200., 245.), vec2(30., 260.)),
-            EnemyBundle::new_wall(asset_server, vec2(200., 245.), vec2(30., 260.)),
-            EnemyBundle::new_wall(asset_server, vec2(-200., -210.), vec2(30., 310.)),
-            EnemyBundle::new_wall(asset_server, vec2(200., -210.), vec2(30., 310.)),
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-240., 90.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-200., 90.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-160., 90.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-240., -30.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-200., -30.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-160., -30.)),
-            EnemyBundle::new_land(asset_server, vec2(0., 0.), vec2(260., 720.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(160., 90.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(200., 90.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(240., 90.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(160., -30.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(200., -30.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(240., -30.)),
+            EnemyBundle::new_wall(scenes, vec2(-200., 245.), vec2(30., 260.)),
+            EnemyBundle::new_wall(scenes, vec2(200., 245.), vec2(30., 260.)),
+            EnemyBundle::new_wall(scenes, vec2(-200., -210.), vec2(30., 310.)),
+            EnemyBundle::new_wall(scenes, vec2(200., -210.), vec2(30., 310.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-240., 90.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-200., 90.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-160., 90.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-240., -30.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-200., -30.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-160., -30.)),
+            EnemyBundle::new_land(scenes, vec2(0., 0.), vec2(260., 720.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(160., 90.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(200., 90.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(240., 90.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(160., -30.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(200., -30.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(240., -30.)),
         ],
     }
 }
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use bevy::{prelude::*, scene::SceneInstanceReady, transform::helper::TransformHelper};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{enemy::EnemyClass, rating::LevelGoals},
+    levels::{
+        level_file::{LevelFile, LevelPiece},
+        validation::validate,
+    },
+    loading::{AssetCollection, SceneHandles, collect_assets},
+};
+
+// Whole levels laid out in Blender and exported to glTF, in the order they're offered. Enemies
+// are empties or meshes with a Skein EnemyClass component, the player starts at the PlayerStart
+// empty and an empty with LevelInfo names the level. See the README for the axes.
+pub const GLTF_LEVELS: [&str; 1] = ["levels/twin_walls.gltf"];
+
+pub struct GltfLevelPlugin;
+
+impl Plugin for GltfLevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LevelInfo>()
+            .register_type::<PlayerStart>()
+            .init_resource::<ImportedLevels>()
+            // Imported with the rest of the assets so Level Select never opens without them
+            .add_systems(
+                OnEnter(AppState::Loading),
+                start_gltf_level_imports.after(collect_assets),
+            )
+            .add_systems(
+                Update,
+                finish_gltf_level_imports.run_if(any_with_component::<GltfLevelImport>),
+            );
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct LevelInfo {
+    pub name: String,
+    pub notes: String,
+    // Zero leaves the level without a par time
+    pub par_time: f32,
+}
+
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct PlayerStart;
+
+// Levels read from glTF files with their paths, keyed by their place in GLTF_LEVELS since they
+// finish loading in any order
+#[derive(Resource, Debug, Default)]
+pub struct ImportedLevels {
+    levels: BTreeMap<usize, (&'static str, LevelFile)>,
+    // Imports still waiting on their scene, including ones that will fail
+    pending: usize,
+}
+
+impl ImportedLevels {
+    pub fn levels(&self) -> impl Iterator<Item = (&'static str, &LevelFile)> {
+        self.levels
+            .values()
+            .map(|(path, level_file)| (*path, level_file))
+    }
+    pub fn is_settled(&self) -> bool {
+        self.pending == 0
+    }
+}
+
+// The scene is spawned hidden just long enough for Skein to insert the annotated components
+#[derive(Component, Debug)]
+struct GltfLevelImport {
+    index: usize,
+    path: &'static str,
+}
+
+#[derive(Component, Debug)]
+struct GltfLevelReady;
+
+fn start_gltf_level_imports(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut asset_collection: ResMut<AssetCollection>,
+    mut imported_levels: ResMut<ImportedLevels>,
+) {
+    imported_levels.pending = GLTF_LEVELS.len();
+    for (index, path) in GLTF_LEVELS.into_iter().enumerate() {
+        let scene = asset_server.load(GltfAssetLabel::Scene(0).from_asset(path));
+        asset_collection.add(scene.clone().untyped());
+        commands
+            .spawn((
+                Name::new("Gltf Level Import"),
+                GltfLevelImport { index, path },
+                SceneRoot(scene),
+                Visibility::Hidden,
+            ))
+            .observe(
+                |trigger: Trigger<SceneInstanceReady>, mut commands: Commands| {
+                    // Skein's components arrive through commands, so read them next frame
+                    commands.entity(trigger.target()).insert(GltfLevelReady);
+                },
+            );
+    }
+}
+
+fn finish_gltf_level_imports(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    mut imported_levels: ResMut<ImportedLevels>,
+    import_q: Query<(Entity, &GltfLevelImport, &SceneRoot, Has<GltfLevelReady>)>,
+    children_q: Query<&Children>,
+    class_q: Query<&EnemyClass>,
+    info_q: Query<&LevelInfo>,
+    start_q: Query<(), With<PlayerStart>>,
+    transform_helper: TransformHelper,
+) {
+    for (entity, import, scene_root, ready) in import_q {
+        if !ready {
+            if asset_server.load_state(&scene_root.0).is_failed() {
+                warn!("Could not load level {}", import.path);
+                commands.entity(entity).despawn();
+                imported_levels.pending -= 1;
+            }
+            continue;
+        }
+        let mut level_file = LevelFile {
+            name: import.path.to_string(),
+            ..default()
+        };
+        for descendant in children_q.iter_descendants(entity) {
+            if let Ok(info) = info_q.get(descendant) {
+                level_file.name = info.name.clone();
+                level_file.notes = info.notes.clone();
+                level_file.goals = LevelGoals {
+                    par_time: (info.par_time > 0.).then_some(info.par_time),
+                    goal: None,
+                };
+            }
+            let Ok(transform) = transform_helper.compute_global_transform(descendant) else {
+                continue;
+            };
+            let position = transform.translation().truncate();
+            if start_q.contains(descendant) {
+                level_file.start_position = position.into();
+            }
+            // Physics and everything else comes from the class, the same as a hand-written level
+            if let Ok(&class) = class_q.get(descendant) {
+                level_file.pieces.push(LevelPiece {
+                    class,
+                    position: position.into(),
+                    scale: transform.scale().truncate().into(),
+                });
+            }
+        }
+        commands.entity(entity).despawn();
+        imported_levels.pending -= 1;
+        if level_file.pieces.is_empty() {
+            warn!(
+                "Level {} has no enemies - are the Skein components exported?",
+                import.path
+            );
+            continue;
+        }
+        for problem in validate(&level_file.to_config(&scenes)) {
+            if problem.is_error() {
+                warn!("Level \"{}\": {problem}", level_file.name);
+            } else {
+                info!("Level \"{}\": {problem}", level_file.name);
+            }
+        }
+        imported_levels
+            .levels
+            .insert(import.index, (import.path, level_file));
+    }
+}
@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::{
+        enemy::{EnemyBundle, EnemyClass},
+        level::LevelConfig,
+        level_theme::LevelTheme,
+        rating::LevelGoals,
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+    storage,
+};
+
+// Levels saved by the editor live under this storage folder
+pub const LEVEL_FILES_FOLDER: &str = "levels";
+
+// A level as plain data so it can be saved, loaded and edited outside of the code
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LevelFile {
+    pub name: String,
+    #[serde(default)]
+    pub notes: String,
+    pub start_position: (f32, f32),
+    // Leave out for a single screen
+    #[serde(default)]
+    pub stage: StageConfig,
+    #[serde(default)]
+    pub theme: LevelTheme,
+    #[serde(default)]
+    pub goals: LevelGoals,
+    pub pieces: Vec<LevelPiece>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LevelPiece {
+    pub class: EnemyClass,
+    pub position: (f32, f32),
+    // Only used by Land and Walls
+    #[serde(default = "unit_scale")]
+    pub scale: (f32, f32),
+}
+
+fn unit_scale() -> (f32, f32) {
+    (1., 1.)
+}
+
+impl Default for LevelFile {
+    fn default() -> Self {
+        Self {
+            name: "Untitled".to_string(),
+            notes: String::new(),
+            start_position: (0., -300.),
+            stage: StageConfig::default(),
+            theme: LevelTheme::default(),
+            goals: LevelGoals::default(),
+            pieces: Vec::new(),
+        }
+    }
+}
+
+impl LevelPiece {
+    pub fn new(class: EnemyClass, position: Vec2) -> Self {
+        let scale = if class.is_scalable() {
+            // Big enough to see and grab, designers resize from here
+            vec2(80., 40.)
+        } else {
+            Vec2::ONE
+        };
+        Self {
+            class,
+            position: position.into(),
+            scale: scale.into(),
+        }
+    }
+    pub fn position(&self) -> Vec2 {
+        self.position.into()
+    }
+    pub fn scale(&self) -> Vec2 {
+        if self.class.is_scalable() {
+            self.scale.into()
+        } else {
+            Vec2::ONE
+        }
+    }
+    pub fn size(&self) -> Vec2 {
+        self.class.size() * self.scale()
+    }
+    pub fn rect(&self) -> Rect {
+        Rect::from_center_size(self.position(), self.size())
+    }
+}
+
+impl LevelFile {
+    pub fn from_config(level_config: &LevelConfig) -> Self {
+        Self {
+            name: level_config.name.clone(),
+            notes: level_config.notes.clone(),
+            start_position: level_config.start_position.into(),
+            stage: level_config.stage,
+            theme: level_config.theme.clone(),
+            goals: level_config.goals,
+            pieces: level_config
+                .enemies
+                .iter()
+                .map(|enemy| LevelPiece {
+                    class: enemy.class(),
+                    position: enemy.position().into(),
+                    scale: enemy.scale().into(),
+                })
+                .collect(),
+        }
+    }
+    pub fn to_config(&self, scenes: &SceneHandles) -> LevelConfig {
+        LevelConfig {
+            name: self.name.clone(),
+            message_id: None,
+            notes: self.notes.clone(),
+            start_position: self.start_position.into(),
+            stage: self.stage,
+            theme: self.theme.clone(),
+            goals: self.goals,
+            enemies: self
+                .pieces
+                .iter()
+                .map(|piece| {
+                    EnemyBundle::new_of_class(scenes, piece.class, piece.position(), piece.scale())
+                })
+                .collect(),
+        }
+    }
+    pub fn start_position(&self) -> Vec2 {
+        self.start_position.into()
+    }
+    // Storage key made from the name so saving again overwrites the same file
+    pub fn key(&self) -> String {
+        let slug = self
+            .name
+            .trim()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        let slug = if slug.is_empty() { "untitled" } else { &slug };
+        format!("{LEVEL_FILES_FOLDER}/{slug}")
+    }
+    pub fn save(&self) -> String {
+        let key = self.key();
+        storage::save(&key, self);
+        key
+    }
+    pub fn load(key: &str) -> Self {
+        storage::load(key)
+    }
+    // Keys of every level saved by the editor
+    pub fn saved_keys() -> Vec<String> {
+        storage::keys_in(LEVEL_FILES_FOLDER)
+    }
+}
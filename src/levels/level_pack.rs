@@ -0,0 +1,201 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::path::{Component, Path};
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::asset::ron;
+#[cfg(not(target_arch = "wasm32"))]
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::storage;
+use crate::{levels::level_file::LevelFile, localization::Localized};
+
+// Each pack is a folder in the mods directory holding this manifest and the level files it lists
+pub const LEVEL_PACK_MANIFEST: &str = "pack.ron";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LevelPackManifest {
+    pub name: String,
+    // Level file names relative to the pack folder, in the order they're played
+    pub levels: Vec<String>,
+}
+
+impl LevelPackManifest {
+    // Level files have to sit directly in the pack folder, so a manifest can't reach elsewhere on
+    // disk with absolute paths or ..
+    pub fn check(&self) -> Result<(), String> {
+        if self.levels.is_empty() {
+            return Err(format!("{LEVEL_PACK_MANIFEST} lists no levels"));
+        }
+        match self
+            .levels
+            .iter()
+            .find(|file_name| !is_plain_file_name(file_name))
+        {
+            Some(file_name) => Err(format!(
+                "{LEVEL_PACK_MANIFEST} lists {file_name:?}, which is not a file in the pack folder"
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+fn is_plain_file_name(file_name: &str) -> bool {
+    let mut components = Path::new(file_name).components();
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    )
+}
+
+#[derive(Clone, Debug)]
+pub struct LevelPack {
+    // The folder name, which stays the same when the pack's name is changed
+    pub id: String,
+    pub name: String,
+    pub levels: Vec<LevelFile>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LevelPackError {
+    pub id: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for LevelPackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.id, self.message)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn mods_dir() -> Option<PathBuf> {
+    storage::data_dir().map(|dir| dir.join("mods"))
+}
+
+// Where players are told to put their packs
+#[cfg(not(target_arch = "wasm32"))]
+pub fn mods_dir_description() -> Localized {
+    match mods_dir() {
+        Some(dir) => {
+            Localized::new("custom-levels-mods-dir").with_arg("dir", dir.display().to_string())
+        }
+        None => Localized::new("custom-levels-no-data-dir"),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn mods_dir_description() -> Localized {
+    Localized::new("custom-levels-desktop-only")
+}
+
+// Every folder in the mods directory in name order, whether or not it could be loaded
+#[cfg(not(target_arch = "wasm32"))]
+pub fn discover_level_packs() -> Vec<Result<LevelPack, LevelPackError>> {
+    let Some(entries) = mods_dir().and_then(|dir| std::fs::read_dir(dir).ok()) else {
+        return Vec::new();
+    };
+    let mut folders = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    folders.sort();
+    folders
+        .iter()
+        .map(|folder| load_level_pack(folder))
+        .collect()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn discover_level_packs() -> Vec<Result<LevelPack, LevelPackError>> {
+    Vec::new()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_level_pack(folder: &Path) -> Result<LevelPack, LevelPackError> {
+    let id = folder
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let error = |message: String| LevelPackError {
+        id: id.clone(),
+        message,
+    };
+    let manifest =
+        read_ron::<LevelPackManifest>(&folder.join(LEVEL_PACK_MANIFEST)).map_err(error)?;
+    manifest.check().map_err(error)?;
+    let levels = manifest
+        .levels
+        .iter()
+        .map(|file_name| read_ron::<LevelFile>(&folder.join(file_name)).map_err(error))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(LevelPack {
+        id,
+        name: manifest.name,
+        levels,
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_ron<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| format!("could not read {file_name}: {err}"))?;
+    ron::from_str(&contents).map_err(|err| format!("could not parse {file_name}: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::ron;
+
+    use super::*;
+
+    fn manifest(levels: &[&str]) -> LevelPackManifest {
+        LevelPackManifest {
+            name: "Test".to_string(),
+            levels: levels.iter().map(|level| level.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn manifest_parses_in_order() {
+        let manifest = ron::from_str::<LevelPackManifest>(
+            r#"(name: "Walls", levels: ["first.ron", "second.ron"])"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.name, "Walls");
+        assert_eq!(manifest.levels, ["first.ron", "second.ron"]);
+        assert_eq!(manifest.check(), Ok(()));
+    }
+
+    #[test]
+    fn manifest_without_levels_is_rejected() {
+        assert!(ron::from_str::<LevelPackManifest>(r#"(name: "Walls")"#).is_err());
+        assert!(manifest(&[]).check().is_err());
+    }
+
+    #[test]
+    fn level_files_stay_in_the_pack_folder() {
+        for file_name in [
+            "../other_pack/level.ron",
+            "..",
+            ".",
+            "",
+            "/etc/passwd",
+            "levels/level.ron",
+            "./level.ron",
+        ] {
+            assert!(
+                manifest(&["level.ron", file_name]).check().is_err(),
+                "{file_name:?} should be rejected"
+            );
+        }
+        assert_eq!(manifest(&["level.ron", "..level.ron"]).check(), Ok(()));
+    }
+}
@@ -1,2 +1,8 @@
+pub mod daily;
+pub mod endless;
 pub mod game;
+pub mod gltf_level;
+pub mod level_file;
+pub mod level_pack;
 pub mod training;
+pub mod validation;
@@ -1,12 +1,22 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle, level::LevelConfig, level_theme::LevelTheme, rating::LevelGoals,
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Enemy Bases",
-        notes: "Fire at the Enemy Base to destroy them.\n\nYou aren't safe until you have avoided their explosion.",
+        name: "Enemy Bases".into(),
+        message_id: Some("level-name-enemy-bases"),
+        notes: "Fire at the Enemy Base to destroy them.\n\nYou aren't safe until you have avoided their explosion.".into(),
         start_position: vec2(0., 0.),
-        enemies: vec![EnemyBundle::new_base(asset_server, vec2(0., 330.))],
+        stage: StageConfig::default(),
+        theme: LevelTheme::default(),
+        goals: LevelGoals::par_time(4.),
+        enemies: vec![EnemyBundle::new_base(scenes, vec2(0., 330.))],
     }
 }
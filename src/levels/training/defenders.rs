@@ -1,22 +1,36 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::LevelTheme,
+        rating::{LevelGoal, LevelGoals},
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Defenders",
+        name: "Defenders".into(),
+        message_id: Some("level-name-defenders"),
         notes: concat!(
             "Enemy Bases can be guarded by Defenders.\n",
             "\n",
             "You can destroy the Enemy Defenders or go straight for the Enemy Base.\n",
             "Destroying a base will cause a chain reaction which destroys Enemy Defenders after a short delay.",
-        ),
+        )
+        .into(),
         start_position: vec2(0., 0.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::default(),
+        goals: LevelGoals::new(5., LevelGoal::PlayerDestroysDefenders),
         enemies: vec![
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-60., 200.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-20., 200.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(40., 0.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-60., 200.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-20., 200.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(40., 0.)),
         ],
     }
 }
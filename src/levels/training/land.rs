@@ -1,22 +1,36 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::LevelTheme,
+        rating::{LevelGoal, LevelGoals},
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Land",
+        name: "Land".into(),
+        message_id: Some("level-name-land"),
         notes: concat!(
             "Enemy Bases control Land.\n",
             "\n",
             "You can't destroy it and it can't destroy you.\n",
             "However, it will be destroyed in the chain reaction (after defenders) and you must survive its explosions to pass the level.",
-        ),
+        )
+        .into(),
         start_position: vec2(0., 0.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::default(),
+        goals: LevelGoals::new(6., LevelGoal::PlayerDestroysDefenders),
         enemies: vec![
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_land(asset_server, vec2(0., 0.), vec2(100., 100.)),
-            EnemyBundle::new_tertiary_defender(asset_server, vec2(-100., 200.)),
-            EnemyBundle::new_tertiary_defender(asset_server, vec2(100., 200.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_land(scenes, vec2(0., 0.), vec2(100., 100.)),
+            EnemyBundle::new_tertiary_defender(scenes, vec2(-100., 200.)),
+            EnemyBundle::new_tertiary_defender(scenes, vec2(100., 200.)),
         ],
     }
 }
@@ -1,20 +1,33 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle,
+        level::LevelConfig,
+        level_theme::LevelTheme,
+        rating::{LevelGoal, LevelGoals},
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Additional Waves",
-        notes: "Icons inside Enemy Defenders indicate which defense Wave they are in.\n\nDefender Waves will explode in order.",
+        name: "Additional Waves".into(),
+        message_id: Some("level-name-additional-waves"),
+        notes: "Icons inside Enemy Defenders indicate which defense Wave they are in.\n\nDefender Waves will explode in order.".into(),
         start_position: vec2(0., 0.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::default(),
+        goals: LevelGoals::new(6., LevelGoal::PlayerDestroysDefenders),
         enemies: vec![
-            EnemyBundle::new_primary_defender(asset_server, vec2(-100., 200.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(100., 200.)),
-            EnemyBundle::new_secondary_defender(asset_server, vec2(-100., 0.)),
-            EnemyBundle::new_secondary_defender(asset_server, vec2(100., 0.)),
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_tertiary_defender(asset_server, vec2(-100., -200.)),
-            EnemyBundle::new_tertiary_defender(asset_server, vec2(100., -200.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-100., 200.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(100., 200.)),
+            EnemyBundle::new_secondary_defender(scenes, vec2(-100., 0.)),
+            EnemyBundle::new_secondary_defender(scenes, vec2(100., 0.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_tertiary_defender(scenes, vec2(-100., -200.)),
+            EnemyBundle::new_tertiary_defender(scenes, vec2(100., -200.)),
         ],
     }
 }
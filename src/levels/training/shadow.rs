@@ -1,23 +1,33 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle, level::LevelConfig, level_theme::LevelTheme, rating::LevelGoals,
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Shadow",
-        notes: "Outlined Defenders are invulnerable to projectiles, though you can still shoot past them.\n\nThey will explode before Defenders.",
+        name: "Shadow".into(),
+        message_id: Some("level-name-shadow"),
+        notes: "Outlined Defenders are invulnerable to projectiles, though you can still shoot past them.\n\nThey will explode before Defenders.".into(),
         start_position: vec2(0., 0.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::default(),
+        goals: LevelGoals::par_time(5.),
         enemies: vec![
-            EnemyBundle::new_shadow(asset_server, vec2(-80., 330.)),
-            EnemyBundle::new_shadow(asset_server, vec2(80., 330.)),
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-80., 290.)),
-            EnemyBundle::new_shadow(asset_server, vec2(-40., 290.)),
-            EnemyBundle::new_shadow(asset_server, vec2(0., 290.)),
-            EnemyBundle::new_shadow(asset_server, vec2(40., 290.)),
-            EnemyBundle::new_shadow(asset_server, vec2(80., 290.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(-100., 200.)),
-            EnemyBundle::new_primary_defender(asset_server, vec2(100., 200.)),
+            EnemyBundle::new_shadow(scenes, vec2(-80., 330.)),
+            EnemyBundle::new_shadow(scenes, vec2(80., 330.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_shadow(scenes, vec2(-80., 290.)),
+            EnemyBundle::new_shadow(scenes, vec2(-40., 290.)),
+            EnemyBundle::new_shadow(scenes, vec2(0., 290.)),
+            EnemyBundle::new_shadow(scenes, vec2(40., 290.)),
+            EnemyBundle::new_shadow(scenes, vec2(80., 290.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(-100., 200.)),
+            EnemyBundle::new_primary_defender(scenes, vec2(100., 200.)),
         ],
     }
 }
@@ -1,15 +1,25 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{enemy::EnemyBundle, level::LevelConfig};
+use crate::{
+    gameplay::{
+        enemy::EnemyBundle, level::LevelConfig, level_theme::LevelTheme, rating::LevelGoals,
+        stage::StageConfig,
+    },
+    loading::SceneHandles,
+};
 
-pub fn get_config(asset_server: &AssetServer) -> LevelConfig {
+pub fn get_config(scenes: &SceneHandles) -> LevelConfig {
     LevelConfig {
-        name: "Enemy Walls",
-        notes: "You can't destroy Enemy Walls and your projectiles have no effect on them.\n\nFortunately, they don't explode.",
+        name: "Enemy Walls".into(),
+        message_id: Some("level-name-enemy-walls"),
+        notes: "You can't destroy Enemy Walls and your projectiles have no effect on them.\n\nFortunately, they don't explode.".into(),
         start_position: vec2(0., -300.),
+        stage: StageConfig::default(),
+        theme: LevelTheme::default(),
+        goals: LevelGoals::par_time(5.),
         enemies: vec![
-            EnemyBundle::new_base(asset_server, vec2(0., 330.)),
-            EnemyBundle::new_wall(asset_server, vec2(0., 0.), vec2(200., 40.)),
+            EnemyBundle::new_base(scenes, vec2(0., 330.)),
+            EnemyBundle::new_wall(scenes, vec2(0., 0.), vec2(200., 40.)),
         ],
     }
 }
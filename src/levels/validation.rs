@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::{
+        enemy::EnemyClass, game_run::GameRun, level::LevelConfig, level_theme::LevelTheme,
+        player::PLAYER_SIZE,
+    },
+    loading::SceneHandles,
+    localization::Localized,
+};
+
+// Rounding in computed layouts shouldn't count as a placement mistake
+const TOLERANCE: f32 = 0.01;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LevelProblem {
+    OutOfBounds {
+        index: usize,
+        class: EnemyClass,
+        position: Vec2,
+    },
+    StartInsideWall {
+        index: usize,
+        position: Vec2,
+    },
+    StartInsideLand {
+        index: usize,
+        position: Vec2,
+    },
+    OverlappingDefenders {
+        index: usize,
+        other_index: usize,
+        position: Vec2,
+    },
+    NoRequiredEnemies,
+    BackgroundOutsideAssets {
+        path: String,
+    },
+}
+
+impl std::fmt::Display for LevelProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelProblem::OutOfBounds {
+                index,
+                class,
+                position,
+            } => write!(
+                f,
+                "enemy {index} ({class}) at {position} is outside the stage"
+            ),
+            LevelProblem::StartInsideWall { index, position } => {
+                write!(f, "start position is inside wall {index} at {position}")
+            }
+            LevelProblem::StartInsideLand { index, position } => {
+                write!(f, "start position is inside land {index} at {position}")
+            }
+            LevelProblem::OverlappingDefenders {
+                index,
+                other_index,
+                position,
+            } => write!(
+                f,
+                "enemy {index} at {position} overlaps defender {other_index}"
+            ),
+            LevelProblem::NoRequiredEnemies => write!(f, "no enemy needs to be destroyed"),
+            LevelProblem::BackgroundOutsideAssets { path } => {
+                write!(f, "background {path:?} is not a path inside assets")
+            }
+        }
+    }
+}
+
+impl LevelProblem {
+    // Display is kept in English for logs and the level bot, this is what players see
+    pub fn message(&self) -> Localized {
+        let at = |localized: Localized, position: &Vec2| {
+            localized
+                .with_arg("x", format!("{:.0}", position.x))
+                .with_arg("y", format!("{:.0}", position.y))
+        };
+        match self {
+            LevelProblem::OutOfBounds {
+                index, position, ..
+            } => at(
+                Localized::new("level-problem-out-of-bounds").with_arg("index", *index),
+                position,
+            ),
+            LevelProblem::StartInsideWall { index, position } => at(
+                Localized::new("level-problem-start-inside-wall").with_arg("index", *index),
+                position,
+            ),
+            LevelProblem::StartInsideLand { index, position } => at(
+                Localized::new("level-problem-start-inside-land").with_arg("index", *index),
+                position,
+            ),
+            LevelProblem::OverlappingDefenders {
+                index,
+                other_index,
+                position,
+            } => at(
+                Localized::new("level-problem-overlapping-defenders")
+                    .with_arg("index", *index)
+                    .with_arg("other", *other_index),
+                position,
+            ),
+            LevelProblem::NoRequiredEnemies => Localized::new("level-problem-no-required-enemies"),
+            LevelProblem::BackgroundOutsideAssets { path } => {
+                Localized::new("level-problem-background-outside-assets")
+                    .with_arg("path", path.clone())
+            }
+        }
+    }
+    // Starting on land is a mistake in most layouts, but levels like the Land training level and
+    // the paths start there on purpose so it's only worth a look rather than failing the check.
+    // A background that can't be loaded is swapped for the generic one so the level still plays
+    pub fn is_error(&self) -> bool {
+        !matches!(
+            self,
+            LevelProblem::StartInsideLand { .. } | LevelProblem::BackgroundOutsideAssets { .. }
+        )
+    }
+}
+
+fn overlaps(a: Rect, b: Rect) -> bool {
+    let intersection = a.intersect(b);
+    !intersection.is_empty() && intersection.width().min(intersection.height()) > TOLERANCE
+}
+
+fn is_defender(class: EnemyClass) -> bool {
+    matches!(
+        class,
+        EnemyClass::DefenderOne
+            | EnemyClass::DefenderTwo
+            | EnemyClass::DefenderThree
+            | EnemyClass::Shadow
+    )
+}
+
+pub fn validate(level_config: &LevelConfig) -> Vec<LevelProblem> {
+    let mut problems = Vec::new();
+    let stage = level_config.stage.rect().inflate(TOLERANCE);
+    let player = Rect::from_center_size(level_config.start_position, PLAYER_SIZE);
+    let enemies = level_config
+        .enemies
+        .iter()
+        .map(|enemy| {
+            (
+                enemy.class(),
+                enemy.position(),
+                Rect::from_center_size(enemy.position(), enemy.size()),
+            )
+        })
+        .collect::<Vec<_>>();
+    for (index, &(class, position, rect)) in enemies.iter().enumerate() {
+        // Walls can run past the edge so no gap is left between them and it, as the paths do
+        let hangs_off_edge = class == EnemyClass::Wall && stage.contains(position);
+        if stage.union(rect) != stage && !hangs_off_edge {
+            problems.push(LevelProblem::OutOfBounds {
+                index,
+                class,
+                position,
+            });
+        }
+        match class {
+            EnemyClass::Wall if overlaps(player, rect) => {
+                problems.push(LevelProblem::StartInsideWall { index, position });
+            }
+            EnemyClass::Land if overlaps(player, rect) => {
+                problems.push(LevelProblem::StartInsideLand { index, position });
+            }
+            _ => {}
+        }
+        if is_defender(class) {
+            for (other_index, &(other_class, _, other_rect)) in
+                enemies.iter().enumerate().skip(index + 1)
+            {
+                if is_defender(other_class) && overlaps(rect, other_rect) {
+                    problems.push(LevelProblem::OverlappingDefenders {
+                        index,
+                        other_index,
+                        position,
+                    });
+                }
+            }
+        }
+    }
+    if !level_config
+        .enemies
+        .iter()
+        .any(|enemy| enemy.requires_destruction())
+    {
+        problems.push(LevelProblem::NoRequiredEnemies);
+    }
+    if let Some(path) = &level_config.theme.background {
+        if !LevelTheme::is_asset_path(path) {
+            problems.push(LevelProblem::BackgroundOutsideAssets { path: path.clone() });
+        }
+    }
+    problems
+}
+
+// Every level that can be reached from the menus, with the problems found in each
+pub fn validate_registered_levels(scenes: &SceneHandles) -> Vec<(String, Vec<LevelProblem>)> {
+    GameRun::game_levels()
+        .into_iter()
+        .chain(GameRun::training_levels())
+        .map(|get_config| {
+            let level_config = get_config(scenes);
+            let problems = validate(&level_config);
+            (level_config.name, problems)
+        })
+        .collect()
+}
@@ -1,10 +1,18 @@
 pub mod app_state;
+pub mod color_palette;
 #[cfg(debug_assertions)]
 pub mod debug;
+pub mod editor;
 pub mod gameplay;
 pub mod levels;
+pub mod loading;
+pub mod localization;
 pub mod menu;
 pub mod menus;
+pub mod music;
 pub mod screen;
 pub mod screens;
+pub mod settings;
+pub mod sound;
+pub mod storage;
 pub mod window;
@@ -0,0 +1,203 @@
+use bevy::{asset::RecursiveDependencyLoadState, platform::collections::HashMap, prelude::*};
+
+use crate::{app_state::AppState, gameplay::enemy::EnemyClass};
+
+// Every scene spawned during gameplay lives here so nothing else needs to know an asset path
+const BACKGROUND_SCENE: &str = "backgrounds/generic-background.glb";
+const PLAYER_SCENE: &str = "player-ship/player-ship.glb";
+
+fn enemy_scene_path(class: EnemyClass) -> &'static str {
+    match class {
+        EnemyClass::Base => "enemies/enemy-one-base.glb",
+        EnemyClass::Shadow => "enemies/enemy-shadow.glb",
+        EnemyClass::DefenderOne => "enemies/enemy-defender-one.glb",
+        EnemyClass::DefenderTwo => "enemies/enemy-defender-two.glb",
+        EnemyClass::DefenderThree => "enemies/enemy-defender-three.glb",
+        EnemyClass::Land => "enemies/enemy-land.glb",
+        EnemyClass::Wall => "enemies/enemy-wall.glb",
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExplosionKind {
+    Base,
+    Defender,
+    Land,
+    Player,
+}
+
+impl ExplosionKind {
+    pub fn all() -> [Self; 4] {
+        [Self::Base, Self::Defender, Self::Land, Self::Player]
+    }
+    // Walls can't be destroyed so they never explode
+    pub fn of_class(class: EnemyClass) -> Option<Self> {
+        match class {
+            EnemyClass::Base => Some(Self::Base),
+            EnemyClass::DefenderOne
+            | EnemyClass::DefenderTwo
+            | EnemyClass::DefenderThree
+            | EnemyClass::Shadow => Some(Self::Defender),
+            EnemyClass::Land => Some(Self::Land),
+            EnemyClass::Wall => None,
+        }
+    }
+    fn scene_path(&self) -> &'static str {
+        match self {
+            Self::Base => "explosions/enemy-base-explosion.glb",
+            Self::Defender => "explosions/enemy-explosion.glb",
+            Self::Land => "explosions/enemy-land-explosion.glb",
+            Self::Player => "player-explosion/player-explosion.glb",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProjectileKind {
+    Player,
+}
+
+impl ProjectileKind {
+    pub fn all() -> [Self; 1] {
+        [Self::Player]
+    }
+    fn scene_path(&self) -> &'static str {
+        match self {
+            Self::Player => "projectiles/player-projectile.glb",
+        }
+    }
+}
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SceneHandles>()
+            .init_resource::<AssetCollection>()
+            .add_systems(OnEnter(AppState::Loading), collect_assets);
+    }
+}
+
+// Built once so spawners clone a handle instead of hashing a path on every spawn
+#[derive(Resource, Clone, Debug)]
+pub struct SceneHandles {
+    background: Handle<Scene>,
+    player: Handle<Scene>,
+    enemies: HashMap<EnemyClass, Handle<Scene>>,
+    explosions: HashMap<ExplosionKind, Handle<Scene>>,
+    projectiles: HashMap<ProjectileKind, Handle<Scene>>,
+}
+
+impl FromWorld for SceneHandles {
+    fn from_world(world: &mut World) -> Self {
+        Self::new(world.resource::<AssetServer>())
+    }
+}
+
+impl SceneHandles {
+    pub fn new(asset_server: &AssetServer) -> Self {
+        let load =
+            |path: &'static str| asset_server.load(GltfAssetLabel::Scene(0).from_asset(path));
+        Self {
+            background: load(BACKGROUND_SCENE),
+            player: load(PLAYER_SCENE),
+            enemies: EnemyClass::in_order()
+                .into_iter()
+                .map(|class| (class, load(enemy_scene_path(class))))
+                .collect(),
+            explosions: ExplosionKind::all()
+                .into_iter()
+                .map(|kind| (kind, load(kind.scene_path())))
+                .collect(),
+            projectiles: ProjectileKind::all()
+                .into_iter()
+                .map(|kind| (kind, load(kind.scene_path())))
+                .collect(),
+        }
+    }
+    pub fn background(&self) -> SceneRoot {
+        SceneRoot(self.background.clone())
+    }
+    pub fn player(&self) -> SceneRoot {
+        SceneRoot(self.player.clone())
+    }
+    pub fn enemy(&self, class: EnemyClass) -> SceneRoot {
+        SceneRoot(self.enemies[&class].clone())
+    }
+    pub fn explosion(&self, kind: ExplosionKind) -> SceneRoot {
+        SceneRoot(self.explosions[&kind].clone())
+    }
+    pub fn projectile(&self, kind: ProjectileKind) -> SceneRoot {
+        SceneRoot(self.projectiles[&kind].clone())
+    }
+    fn handles(&self) -> impl Iterator<Item = &Handle<Scene>> {
+        [&self.background, &self.player]
+            .into_iter()
+            .chain(self.enemies.values())
+            .chain(self.explosions.values())
+            .chain(self.projectiles.values())
+    }
+}
+
+// Holds on to the handles so the assets stay loaded for the whole session
+#[derive(Resource, Debug, Default)]
+pub struct AssetCollection {
+    handles: Vec<UntypedHandle>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct LoadProgress {
+    pub loaded: usize,
+    pub total: usize,
+    pub failed: Vec<String>,
+}
+
+impl LoadProgress {
+    // Nothing is still loading, though some assets may have failed
+    pub fn is_settled(&self) -> bool {
+        self.loaded + self.failed.len() == self.total
+    }
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.
+        } else {
+            (self.loaded + self.failed.len()) as f32 / self.total as f32
+        }
+    }
+}
+
+impl AssetCollection {
+    // For assets that aren't gameplay scenes but still need to load before the title screen
+    pub fn add(&mut self, handle: UntypedHandle) {
+        self.handles.push(handle);
+    }
+    pub fn progress(&self, asset_server: &AssetServer) -> LoadProgress {
+        let mut progress = LoadProgress {
+            total: self.handles.len(),
+            ..default()
+        };
+        for handle in &self.handles {
+            // Scenes aren't ready until their meshes and materials are
+            match asset_server.recursive_dependency_load_state(handle) {
+                RecursiveDependencyLoadState::Loaded => progress.loaded += 1,
+                RecursiveDependencyLoadState::Failed(_) => progress.failed.push(
+                    handle
+                        .path()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("{:?}", handle.id())),
+                ),
+                _ => {}
+            }
+        }
+        progress
+    }
+}
+
+pub fn collect_assets(mut commands: Commands, scene_handles: Res<SceneHandles>) {
+    commands.insert_resource(AssetCollection {
+        handles: scene_handles
+            .handles()
+            .map(|handle| handle.clone().untyped())
+            .collect(),
+    });
+}
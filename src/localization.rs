@@ -0,0 +1,267 @@
+use bevy::prelude::*;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue, concurrent::FluentBundle};
+use unic_langid::LanguageIdentifier;
+
+use crate::settings::Settings;
+
+// Used for anything the chosen language is missing
+pub const DEFAULT_LANGUAGE: &str = "en-US";
+
+// Compiled in so switching is instant and works the same on the web
+const LOCALES: [(&str, &str); 2] = [
+    ("en-US", include_str!("../assets/locales/en-US/main.ftl")),
+    ("es-ES", include_str!("../assets/locales/es-ES/main.ftl")),
+];
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        // Switched to the saved language as soon as the settings are seen
+        app.insert_resource(Localization::new(DEFAULT_LANGUAGE))
+            .add_systems(
+                Update,
+                (
+                    switch_language.run_if(resource_changed::<Settings>),
+                    update_localized_text,
+                )
+                    .chain(),
+            );
+    }
+}
+
+pub fn languages() -> impl Iterator<Item = &'static str> {
+    LOCALES.into_iter().map(|(language, _)| language)
+}
+
+// The one after the given language, wrapping around
+pub fn next_language(language: &str) -> &'static str {
+    let position = languages().position(|other| other == language);
+    let next = position.map_or(0, |position| (position + 1) % LOCALES.len());
+    LOCALES[next].0
+}
+
+// The chosen language, then others sharing its primary language, then the default
+fn fallback_chain(language: &str) -> Vec<(&'static str, &'static str)> {
+    let requested = language.parse::<LanguageIdentifier>().ok();
+    let mut chain = vec![];
+    let exact = LOCALES.iter().filter(|(id, _)| *id == language);
+    let related = LOCALES.iter().filter(|(id, _)| {
+        let Some(requested) = &requested else {
+            return false;
+        };
+        id.parse::<LanguageIdentifier>()
+            .is_ok_and(|other| other.language == requested.language)
+    });
+    let default = LOCALES.iter().filter(|(id, _)| *id == DEFAULT_LANGUAGE);
+    for locale in exact.chain(related).chain(default) {
+        if !chain.contains(locale) {
+            chain.push(*locale);
+        }
+    }
+    chain
+}
+
+fn bundle(language: &str, source: &str) -> Option<FluentBundle<FluentResource>> {
+    let Ok(id) = language.parse::<LanguageIdentifier>() else {
+        warn!("Unknown language \"{language}\"");
+        return None;
+    };
+    let resource =
+        FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, errors)| {
+            for error in errors {
+                warn!("Problem in {language} translations: {error:?}");
+            }
+            resource
+        });
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Unicode isolation marks show up as boxes in the game's font
+    bundle.set_use_isolating(false);
+    if let Err(errors) = bundle.add_resource(resource) {
+        for error in errors {
+            warn!("Problem in {language} translations: {error}");
+        }
+    }
+    Some(bundle)
+}
+
+#[derive(Resource)]
+pub struct Localization {
+    language: String,
+    // Tried in order until one has the message
+    bundles: Vec<FluentBundle<FluentResource>>,
+}
+
+impl Localization {
+    pub fn new(language: &str) -> Self {
+        Self {
+            language: language.to_string(),
+            bundles: fallback_chain(language)
+                .into_iter()
+                .filter_map(|(language, source)| bundle(language, source))
+                .collect(),
+        }
+    }
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+    // Ids with a dot look up an attribute, like "level-name-defenders.notes"
+    fn lookup(&self, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let (message_id, attribute) = match id.split_once('.') {
+            Some((message_id, attribute)) => (message_id, Some(attribute)),
+            None => (id, None),
+        };
+        self.bundles.iter().find_map(|bundle| {
+            let message = bundle.get_message(message_id)?;
+            let pattern = match attribute {
+                Some(attribute) => message.get_attribute(attribute)?.value(),
+                None => message.value()?,
+            };
+            let mut errors = vec![];
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+            for error in errors {
+                warn!("Problem formatting \"{id}\": {error}");
+            }
+            Some(text.into_owned())
+        })
+    }
+    pub fn has_message(&self, id: &str) -> bool {
+        self.lookup(id, None).is_some()
+    }
+    pub fn text(&self, id: &str) -> String {
+        self.format(&Localized::new(id))
+    }
+    pub fn format(&self, localized: &Localized) -> String {
+        let args = localized.fluent_args();
+        if !localized.id.is_empty() {
+            if let Some(text) = self.lookup(&localized.id, args.as_ref()) {
+                return text;
+            }
+        }
+        match &localized.fallback {
+            Some(fallback) => fallback.clone(),
+            None => {
+                warn!("No translation for \"{}\"", localized.id);
+                localized.id.clone()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocalizedArg {
+    Number(f64),
+    String(String),
+}
+
+impl From<u32> for LocalizedArg {
+    fn from(value: u32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<usize> for LocalizedArg {
+    fn from(value: usize) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<f32> for LocalizedArg {
+    fn from(value: f32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<&str> for LocalizedArg {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for LocalizedArg {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+// Keeps the Text or TextSpan on the same entity in the current language
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct Localized {
+    pub id: String,
+    pub args: Vec<(&'static str, LocalizedArg)>,
+    // Shown as is when no language has the message, such as for custom level names
+    pub fallback: Option<String>,
+}
+
+impl Localized {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            args: vec![],
+            fallback: None,
+        }
+    }
+    pub fn with_arg(mut self, name: &'static str, value: impl Into<LocalizedArg>) -> Self {
+        self.args.push((name, value.into()));
+        self
+    }
+    pub fn with_fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+    // The same in every language, such as names players gave their own levels
+    pub fn verbatim(text: impl Into<String>) -> Self {
+        Self::new(String::new()).with_fallback(text)
+    }
+    // Built in levels have a message, anything else keeps the name it was given
+    pub fn level_name(message_id: Option<&str>, name: &str) -> Self {
+        match message_id {
+            Some(message_id) => Self::new(message_id).with_fallback(name),
+            None => Self::verbatim(name),
+        }
+    }
+    pub fn level_notes(message_id: Option<&str>, notes: &str) -> Self {
+        match message_id {
+            Some(message_id) => Self::new(format!("{message_id}.notes")).with_fallback(notes),
+            None => Self::verbatim(notes),
+        }
+    }
+    fn fluent_args(&self) -> Option<FluentArgs<'_>> {
+        if self.args.is_empty() {
+            return None;
+        }
+        let mut args = FluentArgs::new();
+        for (name, value) in &self.args {
+            let value = match value {
+                LocalizedArg::Number(number) => FluentValue::from(*number),
+                LocalizedArg::String(string) => FluentValue::from(string.as_str()),
+            };
+            args.set(*name, value);
+        }
+        Some(args)
+    }
+}
+
+fn switch_language(settings: Res<Settings>, mut localization: ResMut<Localization>) {
+    if settings.language != localization.language() {
+        *localization = Localization::new(&settings.language);
+    }
+}
+
+fn update_localized_text(
+    localization: Res<Localization>,
+    text_q: Query<(Ref<Localized>, Option<&mut Text>, Option<&mut TextSpan>)>,
+) {
+    let language_changed = localization.is_changed();
+    for (localized, text, span) in text_q {
+        if !language_changed && !localized.is_changed() {
+            continue;
+        }
+        let value = localization.format(&localized);
+        if let Some(mut text) = text {
+            text.0 = value;
+        } else if let Some(mut span) = span {
+            span.0 = value;
+        }
+    }
+}
@@ -5,13 +5,26 @@ use bevy_skein::SkeinPlugin;
 use maxx_obliterate::debug::DebugPlugin;
 use maxx_obliterate::{
     app_state::AppStatePlugin,
+    color_palette::ColorPalettePlugin,
+    editor::EditorPlugin,
     gameplay::{
-        collisions::CollisionPlugin, enemy::EnemyPlugin, energy::EnergyPlugin,
-        explosion::ExplosionPlugin, game_run::GameRunPlugin, level::LevelPlugin,
-        player::PlayerPlugin, stage::StagePlugin,
+        camera_effects::CameraEffectsPlugin, chain_preview::ChainPreviewPlugin,
+        collisions::CollisionPlugin, daily_challenge::DailyChallengePlugin,
+        danger_zone::DangerZonePlugin, enemy::EnemyPlugin, energy::EnergyPlugin,
+        explosion::ExplosionPlugin, game_run::GameRunPlugin, high_scores::HighScoresPlugin,
+        level::LevelPlugin, level_packs::LevelPacksPlugin, level_theme::LevelThemePlugin,
+        particles::ParticlePlugin, player::PlayerPlugin, pool::PoolPlugin, rating::RatingPlugin,
+        score::ScorePlugin, stage::StagePlugin, stage_bounds::StageBoundsPlugin,
+        time_attack::TimeAttackPlugin, wave_markers::WaveMarkerPlugin,
     },
+    levels::gltf_level::GltfLevelPlugin,
+    loading::LoadingPlugin,
+    localization::LocalizationPlugin,
     menu::MenuPlugin,
+    music::MusicPlugin,
     screen::ScreenPlugin,
+    settings::SettingsPlugin,
+    sound::SoundPlugin,
     window::AppWindowPlugin,
 };
 
@@ -48,18 +61,44 @@ impl Plugin for AppPlugin {
         app.add_plugins((
             AppStatePlugin,
             AppWindowPlugin,
-            CollisionPlugin,
+            ColorPalettePlugin,
             #[cfg(debug_assertions)]
             DebugPlugin,
+            EditorPlugin,
+            LoadingPlugin,
+            LocalizationPlugin,
+            MenuPlugin,
+            MusicPlugin,
+            ScreenPlugin,
+            SettingsPlugin,
+            SoundPlugin,
+        ));
+        app.add_plugins((
+            CameraEffectsPlugin,
+            ChainPreviewPlugin,
+            CollisionPlugin,
+            DailyChallengePlugin,
+            DangerZonePlugin,
             EnemyPlugin,
             EnergyPlugin,
             ExplosionPlugin,
             GameRunPlugin,
+            GltfLevelPlugin,
+            HighScoresPlugin,
+        ));
+        app.add_plugins((
             LevelPlugin,
-            MenuPlugin,
+            LevelPacksPlugin,
+            LevelThemePlugin,
+            ParticlePlugin,
             PlayerPlugin,
-            ScreenPlugin,
+            PoolPlugin,
+            RatingPlugin,
+            ScorePlugin,
             StagePlugin,
+            StageBoundsPlugin,
+            TimeAttackPlugin,
+            WaveMarkerPlugin,
         ));
     }
 }
@@ -7,6 +7,8 @@ use bevy::{
 use bevy_flair::prelude::*;
 use std::collections::HashSet;
 
+use crate::localization::Localized;
+
 pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
@@ -23,6 +25,7 @@ impl Plugin for MenuPlugin {
 
         // Navigation
         app.init_resource::<ActionState>()
+            .init_resource::<TextEntryFocus>()
             .add_event::<ButtonActivate>()
             .add_observer(on_click_button_observer)
             .add_observer(on_hover_button_observer)
@@ -111,6 +114,10 @@ impl DirectionalNavigationAction {
     }
 }
 
+// Set while a text field is being typed into, so keys like Space and Enter only reach the field
+#[derive(Resource, Debug, Default)]
+pub struct TextEntryFocus(pub bool);
+
 // This keeps track of the inputs that are currently being pressed
 #[derive(Default, Resource)]
 struct ActionState {
@@ -121,6 +128,7 @@ fn process_inputs(
     mut action_state: ResMut<ActionState>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     gamepad_input: Query<&Gamepad>,
+    text_entry_focus: Res<TextEntryFocus>,
 ) {
     // Reset the set of pressed actions each frame
     // to ensure that we only process each action once
@@ -129,7 +137,7 @@ fn process_inputs(
     for action in DirectionalNavigationAction::variants() {
         // Use just_pressed to ensure that we only process each action once
         // for each time it is pressed
-        if keyboard_input.any_just_pressed(action.keycodes()) {
+        if !text_entry_focus.0 && keyboard_input.any_just_pressed(action.keycodes()) {
             action_state.pressed_actions.insert(action);
         }
     }
@@ -208,6 +216,21 @@ fn on_hover_button_observer(
     }
 }
 
-pub fn button(text: &'static str) -> impl Bundle {
+pub fn button(text: impl Into<String>) -> impl Bundle {
     (Button, Children::spawn_one(Text::new(text)))
 }
+
+// Filled in, and kept up to date, in the current language
+pub fn localized_button(message: Localized) -> impl Bundle {
+    (Button, Children::spawn_one((Text::default(), message)))
+}
+
+// One span per line so each is translated on its own
+pub fn spawn_lines(spawner: &mut ChildSpawner, lines: Vec<Localized>) {
+    for (index, line) in lines.into_iter().enumerate() {
+        if index > 0 {
+            spawner.spawn(TextSpan::new("\n"));
+        }
+        spawner.spawn((TextSpan::default(), line));
+    }
+}
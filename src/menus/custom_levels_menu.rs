@@ -0,0 +1,133 @@
+use bevy::{ecs::spawn::SpawnWith, input_focus::AutoFocus, prelude::*};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        game_run::GameRun,
+        level_packs::{LevelPackRun, LevelPacks, PackProgress},
+    },
+    levels::level_pack::mods_dir_description,
+    localization::Localized,
+    menu::{ButtonActivate, NavigableChildren, localized_button, spawn_lines},
+};
+
+pub fn spawn_custom_levels_menu(
+    mut commands: Commands,
+    asset_server: &AssetServer,
+    level_packs: &LevelPacks,
+    progress: &PackProgress,
+) {
+    let summary = if level_packs.packs.is_empty() {
+        vec![
+            Localized::new("custom-levels-none-found"),
+            mods_dir_description(),
+        ]
+    } else {
+        vec![mods_dir_description()]
+    };
+    // The reasons come from the file system and parser so they're shown as they are
+    let problems = if level_packs.problems.is_empty() {
+        vec![]
+    } else {
+        vec![Localized::new("custom-levels-problems")]
+    };
+    let problem_details = level_packs
+        .problems
+        .iter()
+        .map(|problem| format!("\n{problem}"))
+        .collect::<String>();
+    let pack_buttons = level_packs
+        .packs
+        .iter()
+        .map(|pack| {
+            let (earned, available) = progress.stars(pack);
+            let label = Localized::new("custom-levels-pack")
+                .with_arg("name", pack.name.as_str())
+                .with_arg("cleared", progress.levels_cleared(pack))
+                .with_arg("total", pack.levels.len())
+                .with_arg("earned", earned)
+                .with_arg("available", available);
+            (
+                GameRun::new_level_pack(pack.levels.clone()),
+                LevelPackRun {
+                    pack_id: pack.id.clone(),
+                },
+                label,
+            )
+        })
+        .collect::<Vec<_>>();
+    commands.spawn((
+        StateScoped(AppState::CustomLevels),
+        ClassList::new_with_classes(["globally-center-children", "overlay"]),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        Children::spawn_one((
+            Node::default(),
+            ClassList::new_with_classes(["column"]),
+            children![
+                (
+                    Text::default(),
+                    Localized::new("custom-levels-heading"),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (
+                    Text::default(),
+                    ClassList::new_with_classes(["text"]),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawn_lines(spawner, summary);
+                    })),
+                ),
+                (
+                    Text::default(),
+                    ClassList::new_with_classes(["pack-problems"]),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawn_lines(spawner, problems);
+                        spawner.spawn(TextSpan::new(problem_details));
+                    })),
+                ),
+                (
+                    Name::new("Custom Levels Menu"),
+                    ClassList::new_with_classes(["dialog-menu", "level-select-menu"]),
+                    Node::default(),
+                    NavigableChildren::default(),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        let has_packs = !pack_buttons.is_empty();
+                        pack_buttons.into_iter().enumerate().for_each(
+                            |(index, (game_run, pack_run, label))| {
+                                let mut button = spawner.spawn(localized_button(label));
+                                button.observe(
+                                    move |_trigger: Trigger<ButtonActivate>,
+                                          mut commands: Commands,
+                                          mut next_state: ResMut<NextState<AppState>>,
+                                          game_run_q: Query<Entity, With<GameRun>>| {
+                                        // The last run is still around when coming back from gameplay
+                                        for game_run in game_run_q {
+                                            commands.entity(game_run).despawn();
+                                        }
+                                        commands.spawn((game_run.clone(), pack_run.clone()));
+                                        next_state.set(AppState::ResetGameplay);
+                                    },
+                                );
+                                if index == 0 {
+                                    button.insert(AutoFocus);
+                                }
+                            },
+                        );
+                        let mut main_menu =
+                            spawner.spawn(localized_button(Localized::new("menu-main-menu")));
+                        main_menu.observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut next_state: ResMut<NextState<AppState>>| {
+                                next_state.set(AppState::Title);
+                            },
+                        );
+                        if !has_packs {
+                            main_menu.insert(AutoFocus);
+                        }
+                    })),
+                ),
+            ],
+        )),
+    ));
+}
@@ -0,0 +1,99 @@
+use bevy::{ecs::spawn::SpawnWith, input_focus::AutoFocus, prelude::*};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        daily_challenge::{DailyHistory, today},
+        game_run::{GameRunMode, SelectedGameRunMode},
+    },
+    localization::Localized,
+    menu::{ButtonActivate, NavigableChildren, localized_button, spawn_lines},
+};
+
+const DAILY_HISTORY_ROWS: usize = 10;
+
+fn history_rows(history: &DailyHistory) -> Vec<Localized> {
+    if history.0.is_empty() {
+        return vec![Localized::new("daily-history-empty")];
+    }
+    history
+        .0
+        .iter()
+        .rev()
+        .take(DAILY_HISTORY_ROWS)
+        .map(|(date, result)| {
+            result
+                .summary("daily-history-row")
+                .with_arg("date", date.as_str())
+        })
+        .collect()
+}
+
+pub fn spawn_daily_challenge_menu(
+    mut commands: Commands,
+    asset_server: &AssetServer,
+    history: &DailyHistory,
+) {
+    let date = today();
+    let (status, play_label) = match history.result(&date) {
+        Some(result) => (result.summary("daily-today"), "daily-challenge-practice"),
+        None => (
+            Localized::new("daily-today").with_arg("outcome", "none"),
+            "menu-play",
+        ),
+    };
+    let rows = history_rows(history);
+    commands.spawn((
+        StateScoped(AppState::DailyChallenge),
+        ClassList::new_with_classes(["globally-center-children", "overlay"]),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        Children::spawn_one((
+            Node::default(),
+            ClassList::new_with_classes(["column", "high-scores"]),
+            children![
+                (
+                    Text::default(),
+                    Localized::new("daily-challenge-heading"),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (Text::new(date), ClassList::new_with_classes(["text"])),
+                (
+                    Text::default(),
+                    status,
+                    ClassList::new_with_classes(["text"])
+                ),
+                (
+                    Text::default(),
+                    ClassList::new_with_classes(["high-score-rows"]),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawn_lines(spawner, rows);
+                    })),
+                ),
+                (
+                    Name::new("Daily Challenge Menu"),
+                    ClassList::new_with_classes(["dialog-menu"]),
+                    Node::default(),
+                    NavigableChildren::default(),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawner.spawn((localized_button(Localized::new(play_label)), AutoFocus)).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut selected_mode: ResMut<SelectedGameRunMode>,
+                             mut next_state: ResMut<NextState<AppState>>| {
+                                selected_mode.0 = Some(GameRunMode::DailyChallenge);
+                                next_state.set(AppState::ResetGameRun);
+                            },
+                        );
+                        spawner.spawn(localized_button(Localized::new("menu-main-menu"))).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut next_state: ResMut<NextState<AppState>>| {
+                                next_state.set(AppState::Title);
+                            },
+                        );
+                    })),
+                ),
+            ],
+        )),
+    ));
+}
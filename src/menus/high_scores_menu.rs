@@ -0,0 +1,205 @@
+use bevy::{ecs::spawn::SpawnWith, input_focus::AutoFocus, prelude::*};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        game_run::{GameRun, GameRunMode},
+        high_scores::{HighScoreTable, HighScores, PlayerName},
+    },
+    loading::SceneHandles,
+    localization::{Localization, Localized},
+    menu::{ButtonActivate, NavigableChildren, TextEntryFocus, localized_button},
+};
+
+// Every table that can be shown and which one is currently visible
+#[derive(Resource, Debug)]
+pub struct HighScoreTableView {
+    pub tables: Vec<(Localized, HighScoreTable)>,
+    pub index: usize,
+}
+
+impl HighScoreTableView {
+    fn new(scenes: &SceneHandles, high_scores: &HighScores) -> Self {
+        let campaigns = [
+            GameRunMode::Game,
+            GameRunMode::Training,
+            GameRunMode::Endless,
+        ]
+        .into_iter()
+        .map(|mode| {
+            (
+                Localized::new("high-scores-run-title").with_arg("mode", mode.key()),
+                high_scores.campaign(mode).cloned().unwrap_or_default(),
+            )
+        });
+        let levels = GameRun::game_levels()
+            .into_iter()
+            .chain(GameRun::training_levels())
+            .map(|get_config| {
+                let level_config = get_config(scenes);
+                let table = high_scores
+                    .level(&level_config.name)
+                    .cloned()
+                    .unwrap_or_default();
+                (
+                    Localized::level_name(level_config.message_id, &level_config.name),
+                    table,
+                )
+            });
+        // Most recent day first
+        let daily = high_scores.daily.iter().rev().map(|(date, table)| {
+            (
+                Localized::new("high-scores-daily-title").with_arg("date", date.as_str()),
+                table.clone(),
+            )
+        });
+        Self {
+            tables: campaigns.chain(levels).chain(daily).collect(),
+            index: 0,
+        }
+    }
+    pub fn step(&mut self, forward: bool) {
+        let len = self.tables.len();
+        self.index = if forward {
+            (self.index + 1) % len
+        } else {
+            (self.index + len - 1) % len
+        };
+    }
+    pub fn title(&self) -> &Localized {
+        &self.tables[self.index].0
+    }
+    // Formatted in one go so the columns line up under their headers
+    pub fn rows(&self, localization: &Localization) -> String {
+        let table = &self.tables[self.index].1;
+        if table.0.is_empty() {
+            return localization.text("high-scores-empty");
+        }
+        let header = format!(
+            "{:>3} {:<12} {:>7} {:>7} {:<10} {}",
+            "#",
+            localization.text("high-scores-column-name"),
+            localization.text("high-scores-column-score"),
+            localization.text("high-scores-column-time"),
+            localization.text("high-scores-column-date"),
+            localization.text("high-scores-column-difficulty"),
+        );
+        let rows = table.0.iter().enumerate().map(|(rank, entry)| {
+            format!(
+                "{:>2}. {:<12} {:>7} {:>6.2}s {:<10} {}",
+                rank + 1,
+                entry.name,
+                entry.score,
+                entry.time_secs,
+                entry.date,
+                localization.format(
+                    &Localized::new("difficulty").with_arg("difficulty", entry.difficulty.key())
+                ),
+            )
+        });
+        std::iter::once(header)
+            .chain(rows)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct HighScoreTableTitle;
+
+#[derive(Component, Debug)]
+pub struct HighScoreTableRows;
+
+#[derive(Component, Debug)]
+pub struct PlayerNameText;
+
+pub fn player_name_label(player_name: &PlayerName, editing: bool) -> Localized {
+    Localized::new("high-scores-player-name")
+        .with_arg("name", player_name.0.as_str())
+        .with_arg("cursor", if editing { "_" } else { "" })
+}
+
+pub fn spawn_high_scores_menu(
+    mut commands: Commands,
+    asset_server: &AssetServer,
+    scenes: &SceneHandles,
+    high_scores: &HighScores,
+    player_name: &PlayerName,
+) {
+    // The rows are filled in once the view is in place
+    let view = HighScoreTableView::new(scenes, high_scores);
+    let title = view.title().clone();
+    commands.insert_resource(view);
+    commands.insert_resource(TextEntryFocus::default());
+    let name_label = player_name_label(player_name, false);
+    commands.spawn((
+        StateScoped(AppState::HighScores),
+        ClassList::new_with_classes(["globally-center-children", "overlay"]),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        Children::spawn_one((
+            Node::default(),
+            ClassList::new_with_classes(["column", "high-scores"]),
+            children![
+                (
+                    Text::default(),
+                    Localized::new("high-scores-heading"),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (
+                    Text::default(),
+                    title,
+                    HighScoreTableTitle,
+                    ClassList::new_with_classes(["text"])
+                ),
+                (
+                    Text::default(),
+                    HighScoreTableRows,
+                    ClassList::new_with_classes(["high-score-rows"])
+                ),
+                (
+                    Name::new("High Scores Menu"),
+                    ClassList::new_with_classes(["dialog-menu"]),
+                    Node::default(),
+                    NavigableChildren::default(),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawner.spawn((localized_button(Localized::new("high-scores-next-table")), AutoFocus)).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut view: ResMut<HighScoreTableView>| {
+                                view.step(true);
+                            },
+                        );
+                        spawner.spawn(localized_button(Localized::new("high-scores-previous-table"))).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut view: ResMut<HighScoreTableView>| {
+                                view.step(false);
+                            },
+                        );
+                        spawner
+                            .spawn((
+                                Button,
+                                Children::spawn_one((Text::default(), name_label, PlayerNameText)),
+                            ))
+                            .observe(
+                                |_trigger: Trigger<ButtonActivate>,
+                                 mut entry: ResMut<TextEntryFocus>,
+                                 player_name: Res<PlayerName>| {
+                                    entry.0 = !entry.0;
+                                    if !entry.0 {
+                                        player_name.save();
+                                    }
+                                },
+                            );
+                        spawner.spawn(localized_button(Localized::new("menu-main-menu"))).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut next_state: ResMut<NextState<AppState>>| {
+                                next_state.set(AppState::Title);
+                            },
+                        );
+                    })),
+                ),
+            ],
+        )),
+    ));
+}
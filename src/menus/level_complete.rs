@@ -4,10 +4,14 @@ use bevy_flair::style::components::{ClassList, NodeStyleSheet};
 use crate::{
     app_state::AppState,
     gameplay::{
-        game_run::{GameRun, GameRunMode},
+        game_run::{GameRun, GameRunMode, SelectedGameRunMode},
+        high_scores::NewRecords,
         level::{LevelState, LevelStats},
+        rating::{StarRecords, star_text},
     },
-    menu::{ButtonActivate, NavigableChildren, button},
+    loading::SceneHandles,
+    localization::Localized,
+    menu::{ButtonActivate, NavigableChildren, localized_button, spawn_lines},
 };
 
 #[derive(Clone, Debug)]
@@ -37,12 +41,22 @@ enum NextLevelStatus {
     MoreLevels,
     GameComplete,
     SingleLevelRun,
+    TimeAttackComplete,
+    EndlessRun,
+    Playtest,
+    PackComplete,
 }
 
 impl From<&GameRun> for NextLevelStatus {
     fn from(game_run: &GameRun) -> Self {
         match (game_run.mode(), game_run.has_more_levels()) {
-            (GameRunMode::SingleLevel, _) => NextLevelStatus::SingleLevelRun,
+            (GameRunMode::SingleLevel | GameRunMode::DailyChallenge, _) => {
+                NextLevelStatus::SingleLevelRun
+            }
+            (GameRunMode::TimeAttack, false) => NextLevelStatus::TimeAttackComplete,
+            (GameRunMode::Endless, _) => NextLevelStatus::EndlessRun,
+            (GameRunMode::Playtest, _) => NextLevelStatus::Playtest,
+            (GameRunMode::LevelPack, false) => NextLevelStatus::PackComplete,
             (_, true) => NextLevelStatus::MoreLevels,
             (_, false) => NextLevelStatus::GameComplete,
         }
@@ -52,39 +66,109 @@ impl From<&GameRun> for NextLevelStatus {
 pub fn spawn_level_complete_menu(
     mut commands: Commands,
     asset_server: &AssetServer,
+    scenes: &SceneHandles,
     level_stats: &LevelStats,
     game_run: &GameRun,
+    star_records: &StarRecords,
+    new_records: &NewRecords,
+    // Mode specific lines such as time attack splits
+    run_summary: Vec<Localized>,
 ) {
     let next_level_status = NextLevelStatus::from(game_run);
     let level_completion_status = LevelCompletionStatus::from(level_stats);
     let (heading, text) = match (&level_completion_status, &next_level_status) {
-        (LevelCompletionStatus::Survived, NextLevelStatus::MoreLevels) => {
-            ("Level Complete!", "Good job! Keep going?")
-        }
+        (
+            LevelCompletionStatus::Survived,
+            NextLevelStatus::MoreLevels | NextLevelStatus::EndlessRun,
+        ) => ("level-complete-heading", "level-complete-keep-going"),
         (LevelCompletionStatus::Survived, NextLevelStatus::SingleLevelRun) => {
-            ("Level Complete!", "Good job! Replay?")
+            ("level-complete-heading", "level-complete-replay")
+        }
+        (LevelCompletionStatus::Survived, NextLevelStatus::Playtest) => {
+            ("level-complete-heading", "level-complete-playtest")
         }
         (LevelCompletionStatus::Survived, NextLevelStatus::GameComplete) => {
-            ("Congratulations!!!", "You beat the game! Play again?")
+            ("game-complete-heading", "game-complete-text")
+        }
+        (LevelCompletionStatus::Survived, NextLevelStatus::PackComplete) => {
+            ("pack-complete-heading", "pack-complete-text")
+        }
+        (LevelCompletionStatus::Survived, NextLevelStatus::TimeAttackComplete) => {
+            ("time-attack-complete-heading", "time-attack-complete-text")
         }
 
-        (LevelCompletionStatus::LostEnemiesDestroyed, _) => (
-            "Almost!",
-            "You got the enemy, but their explosions got you too! You can do it!",
-        ),
-        (LevelCompletionStatus::LostEnemiesRemaining, _) => ("Player Lost!", "Nice try! Go again?"),
+        (
+            LevelCompletionStatus::LostEnemiesDestroyed
+            | LevelCompletionStatus::LostEnemiesRemaining,
+            NextLevelStatus::EndlessRun,
+        ) => ("run-over-heading", "run-over-text"),
+        (LevelCompletionStatus::LostEnemiesDestroyed, _) => ("almost-heading", "almost-text"),
+        (LevelCompletionStatus::LostEnemiesRemaining, _) => {
+            ("player-lost-heading", "player-lost-text")
+        }
 
-        (LevelCompletionStatus::Error, _) => (
-            "Error: level stats not complete",
-            "Sorry, something went wrong!",
-        ),
+        (LevelCompletionStatus::Error, _) => ("stats-error-heading", "stats-error-text"),
     };
-    let stats = format!(
-        "Level Enemies Destroyed: {}/{}\nin {:.2} secs",
-        level_stats.enemy_counts.total(),
-        level_stats.original_enemy_counts.total(),
-        level_stats.stopwatch.elapsed_secs(),
-    );
+    let mut stats = vec![
+        Localized::new("level-complete-enemies-destroyed")
+            .with_arg("destroyed", level_stats.enemy_counts.total())
+            .with_arg("total", level_stats.original_enemy_counts.total()),
+        Localized::new("level-complete-time").with_arg(
+            "secs",
+            format!("{:.2}", level_stats.stopwatch.elapsed_secs()),
+        ),
+        Localized::new("level-complete-score")
+            .with_arg("score", level_stats.score.total())
+            .with_arg("multiplier", level_stats.score.multiplier),
+    ];
+    if level_stats.success == Some(true) {
+        stats.push(Localized::new("level-complete-stars").with_arg(
+            "stars",
+            star_text(level_stats.stars(), level_stats.goals.max_stars()),
+        ));
+    }
+    if !matches!(
+        game_run.mode(),
+        GameRunMode::SingleLevel | GameRunMode::DailyChallenge | GameRunMode::Playtest
+    ) {
+        stats.push(
+            Localized::new("level-complete-run-score").with_arg("score", game_run.total_score()),
+        );
+    }
+    if game_run.mode() == GameRunMode::Endless && level_stats.success == Some(false) {
+        stats.push(
+            Localized::new("level-complete-levels-cleared")
+                .with_arg("count", game_run.levels_completed()),
+        );
+    }
+    if game_run.mode() == GameRunMode::Game {
+        let level_configs = GameRun::game_levels()
+            .into_iter()
+            .map(|get_config| get_config(scenes))
+            .collect::<Vec<_>>();
+        let (earned, available) = star_records.total(&level_configs);
+        stats.push(
+            Localized::new("level-complete-campaign-stars")
+                .with_arg("earned", earned)
+                .with_arg("available", available),
+        );
+    }
+    stats.extend(run_summary);
+    let new_records = [
+        ("level", new_records.level_rank),
+        ("run", new_records.campaign_rank),
+    ]
+    .into_iter()
+    .filter_map(|(kind, rank)| match rank {
+        Some(0) => Some(Localized::new("new-record").with_arg("kind", kind)),
+        Some(rank) => Some(
+            Localized::new("new-high-score")
+                .with_arg("kind", kind)
+                .with_arg("rank", rank + 1),
+        ),
+        None => None,
+    })
+    .collect::<Vec<_>>();
     let level_stats = level_stats.clone();
     let has_more_levels = game_run.has_more_levels();
     commands.spawn((
@@ -100,20 +184,56 @@ pub fn spawn_level_complete_menu(
             Node::default(),
             ClassList::new_with_classes(["level-complete-dialog"]),
             children![
-                (Text::new(heading), ClassList::new_with_classes(["heading"])),
-                (Text::new(text), ClassList::new_with_classes(["text"])),
-                (Text::new(stats), ClassList::new_with_classes(["text"])),
+                (
+                    Text::default(),
+                    Localized::new(heading),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (
+                    Text::default(),
+                    Localized::new(text),
+                    ClassList::new_with_classes(["text"])
+                ),
+                (
+                    Text::default(),
+                    ClassList::new_with_classes(["text"]),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawn_lines(spawner, stats);
+                    })),
+                ),
+                (
+                    Text::default(),
+                    ClassList::new_with_classes(["new-record"]),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawn_lines(spawner, new_records);
+                    })),
+                ),
                 (
                     Name::new("Level Complete Dialog Menu"),
                     Node::default(),
                     Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
                         match (level_stats.success, next_level_status) {
-                            (Some(true), NextLevelStatus::MoreLevels) => {
+                            (
+                                Some(true),
+                                NextLevelStatus::MoreLevels | NextLevelStatus::EndlessRun,
+                            ) => {
                                 spawner.spawn(level_complete_success_menu(
                                     &level_stats,
                                     has_more_levels,
                                 ));
                             }
+                            (Some(true), NextLevelStatus::TimeAttackComplete) => {
+                                spawner.spawn(play_again_menu(GameRunMode::TimeAttack));
+                            }
+                            (Some(false), NextLevelStatus::EndlessRun) => {
+                                spawner.spawn(play_again_menu(GameRunMode::Endless));
+                            }
+                            (_, NextLevelStatus::Playtest) => {
+                                spawner.spawn(playtest_menu());
+                            }
+                            (Some(true), NextLevelStatus::PackComplete) => {
+                                spawner.spawn(pack_complete_menu());
+                            }
                             _ => {
                                 spawner.spawn(no_advancement_level(&level_stats));
                             }
@@ -133,66 +253,193 @@ fn level_complete_success_menu(_level_stats: &LevelStats, has_more_levels: bool)
         NavigableChildren::default(),
         Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
             if has_more_levels {
-                spawner.spawn((button("Next Level"), AutoFocus)).observe(
-                    |_trigger: Trigger<ButtonActivate>,
-                     mut next_state: ResMut<NextState<AppState>>,
-                     mut game_run: Single<&mut GameRun>| {
-                        if let Err(message) = game_run.advance_current_level() {
-                            // TODO: Show this in the ui
-                            warn!(message);
-                        } else {
+                spawner
+                    .spawn((
+                        localized_button(Localized::new("menu-next-level")),
+                        AutoFocus,
+                    ))
+                    .observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>,
+                         mut game_run: Single<&mut GameRun>| {
+                            if let Err(message) = game_run.advance_current_level() {
+                                // TODO: Show this in the ui
+                                warn!(message);
+                            } else {
+                                next_state.set(AppState::ResetGameplay);
+                            }
+                        },
+                    );
+                spawner
+                    .spawn(localized_button(Localized::new("menu-replay-level")))
+                    .observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
                             next_state.set(AppState::ResetGameplay);
-                        }
-                    },
-                );
-                spawner.spawn(button("Replay Level")).observe(
+                        },
+                    );
+                spawner
+                    .spawn(localized_button(Localized::new("menu-main-menu")))
+                    .observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::Title);
+                        },
+                    );
+            } else {
+                spawner
+                    .spawn((
+                        localized_button(Localized::new("menu-main-menu")),
+                        AutoFocus,
+                    ))
+                    .observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::Title);
+                        },
+                    );
+                spawner
+                    .spawn(localized_button(Localized::new("menu-replay-level")))
+                    .observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::ResetGameplay);
+                        },
+                    );
+            }
+        })),
+    )
+}
+
+fn no_advancement_level(_level_stats: &LevelStats) -> impl Bundle {
+    (
+        Name::new("No Advancement Menu"),
+        Node::default(),
+        ClassList::new_with_classes(["dialog-menu"]),
+        NavigableChildren::default(),
+        // TODO: allow moving ahead if the level has previously been beaten?
+        Children::spawn(SpawnWith(|spawner: &mut ChildSpawner| {
+            spawner
+                .spawn((
+                    localized_button(Localized::new("menu-replay-level")),
+                    AutoFocus,
+                ))
+                .observe(
                     |_trigger: Trigger<ButtonActivate>,
                      mut next_state: ResMut<NextState<AppState>>| {
                         next_state.set(AppState::ResetGameplay);
                     },
                 );
-                spawner.spawn(button("Main Menu")).observe(
+            spawner
+                .spawn(localized_button(Localized::new("menu-main-menu")))
+                .observe(
                     |_trigger: Trigger<ButtonActivate>,
                      mut next_state: ResMut<NextState<AppState>>| {
                         next_state.set(AppState::Title);
                     },
                 );
-            } else {
-                spawner.spawn((button("Main Menu"), AutoFocus)).observe(
+        })),
+    )
+}
+
+// Starts a fresh run of the same mode for runs that can't be continued level by level
+fn play_again_menu(mode: GameRunMode) -> impl Bundle {
+    (
+        Name::new("Play Again Menu"),
+        Node::default(),
+        ClassList::new_with_classes(["dialog-menu"]),
+        NavigableChildren::default(),
+        Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+            spawner
+                .spawn((
+                    localized_button(Localized::new("menu-play-again")),
+                    AutoFocus,
+                ))
+                .observe(
+                    move |_trigger: Trigger<ButtonActivate>,
+                          mut selected_mode: ResMut<SelectedGameRunMode>,
+                          mut next_state: ResMut<NextState<AppState>>| {
+                        selected_mode.0 = Some(mode);
+                        next_state.set(AppState::ResetGameRun);
+                    },
+                );
+            spawner
+                .spawn(localized_button(Localized::new("menu-main-menu")))
+                .observe(
                     |_trigger: Trigger<ButtonActivate>,
                      mut next_state: ResMut<NextState<AppState>>| {
                         next_state.set(AppState::Title);
                     },
                 );
-                spawner.spawn(button("Replay Level")).observe(
+        })),
+    )
+}
+
+fn playtest_menu() -> impl Bundle {
+    (
+        Name::new("Playtest Menu"),
+        Node::default(),
+        ClassList::new_with_classes(["dialog-menu"]),
+        NavigableChildren::default(),
+        Children::spawn(SpawnWith(|spawner: &mut ChildSpawner| {
+            spawner
+                .spawn((
+                    localized_button(Localized::new("menu-back-to-editor")),
+                    AutoFocus,
+                ))
+                .observe(
+                    |_trigger: Trigger<ButtonActivate>,
+                     mut next_state: ResMut<NextState<AppState>>| {
+                        next_state.set(AppState::Editor);
+                    },
+                );
+            spawner
+                .spawn(localized_button(Localized::new("menu-replay-level")))
+                .observe(
                     |_trigger: Trigger<ButtonActivate>,
                      mut next_state: ResMut<NextState<AppState>>| {
                         next_state.set(AppState::ResetGameplay);
                     },
                 );
-            }
         })),
     )
 }
 
-fn no_advancement_level(_level_stats: &LevelStats) -> impl Bundle {
+// Back to the pack list, where the pack can be started again from its first level
+fn pack_complete_menu() -> impl Bundle {
     (
-        Name::new("No Advancement Menu"),
+        Name::new("Pack Complete Menu"),
         Node::default(),
         ClassList::new_with_classes(["dialog-menu"]),
         NavigableChildren::default(),
-        // TODO: allow moving ahead if the level has previously been beaten?
         Children::spawn(SpawnWith(|spawner: &mut ChildSpawner| {
-            spawner.spawn((button("Replay Level"), AutoFocus)).observe(
-                |_trigger: Trigger<ButtonActivate>, mut next_state: ResMut<NextState<AppState>>| {
-                    next_state.set(AppState::ResetGameplay);
-                },
-            );
-            spawner.spawn(button("Main Menu")).observe(
-                |_trigger: Trigger<ButtonActivate>, mut next_state: ResMut<NextState<AppState>>| {
-                    next_state.set(AppState::Title);
-                },
-            );
+            spawner
+                .spawn((
+                    localized_button(Localized::new("menu-custom-levels")),
+                    AutoFocus,
+                ))
+                .observe(
+                    |_trigger: Trigger<ButtonActivate>,
+                     mut next_state: ResMut<NextState<AppState>>| {
+                        next_state.set(AppState::CustomLevels);
+                    },
+                );
+            spawner
+                .spawn(localized_button(Localized::new("menu-replay-level")))
+                .observe(
+                    |_trigger: Trigger<ButtonActivate>,
+                     mut next_state: ResMut<NextState<AppState>>| {
+                        next_state.set(AppState::ResetGameplay);
+                    },
+                );
+            spawner
+                .spawn(localized_button(Localized::new("menu-main-menu")))
+                .observe(
+                    |_trigger: Trigger<ButtonActivate>,
+                     mut next_state: ResMut<NextState<AppState>>| {
+                        next_state.set(AppState::Title);
+                    },
+                );
         })),
     )
 }
@@ -3,14 +3,54 @@ use bevy_flair::style::components::{ClassList, NodeStyleSheet};
 
 use crate::{
     app_state::AppState,
-    gameplay::game_run::{GameRun, GameRunMode, SelectedGameRunMode},
-    menu::{ButtonActivate, NavigableChildren, button},
+    gameplay::{
+        game_run::{GameRun, GameRunMode, SelectedGameRunMode},
+        level::LevelConfig,
+        rating::{StarRecords, star_text},
+    },
+    levels::gltf_level::ImportedLevels,
+    loading::SceneHandles,
+    localization::Localized,
+    menu::{ButtonActivate, NavigableChildren},
 };
 
-pub fn spawn_level_select_menu(mut commands: Commands, asset_server: &AssetServer) {
-    let level_configs = GameRun::game_levels()
+pub fn spawn_level_select_menu(
+    mut commands: Commands,
+    asset_server: &AssetServer,
+    scenes: &SceneHandles,
+    star_records: &StarRecords,
+    imported_levels: &ImportedLevels,
+) {
+    let get_configs = GameRun::game_levels();
+    let level_configs = get_configs
+        .iter()
+        .map(|get_config| get_config(scenes))
+        .collect::<Vec<_>>();
+    let (earned_stars, available_stars) = star_records.total(&level_configs);
+    // Imported levels keep their records under their path rather than their name
+    let label = |game_run: &GameRun, level_config: &LevelConfig| {
+        let stars = star_text(
+            star_records.best(&game_run.record_key(&level_config.name)),
+            level_config.goals.max_stars(),
+        );
+        (
+            Localized::level_name(level_config.message_id, &level_config.name),
+            stars,
+        )
+    };
+    let level_buttons = get_configs
         .into_iter()
-        .map(|get_config| (get_config, get_config(asset_server)))
+        .zip(level_configs.iter())
+        .map(|(get_config, level_config)| {
+            let game_run = GameRun::new_single_level(get_config);
+            let label = label(&game_run, level_config);
+            (game_run, label)
+        })
+        .chain(imported_levels.levels().map(|(path, level_file)| {
+            let game_run = GameRun::new_imported_level(path, level_file.clone());
+            let label = label(&game_run, &level_file.to_config(scenes));
+            (game_run, label)
+        }))
         .collect::<Vec<_>>();
     commands.spawn((
         StateScoped(AppState::GameRun),
@@ -22,18 +62,35 @@ pub fn spawn_level_select_menu(mut commands: Commands, asset_server: &AssetServe
             ClassList::new_with_classes(["column"]),
             children![
                 (
-                    Text::new("Select a Level"),
+                    Text::default(),
+                    Localized::new("level-select-heading"),
                     ClassList::new_with_classes(["heading"])
                 ),
+                (
+                    Text::default(),
+                    Localized::new("level-complete-campaign-stars")
+                        .with_arg("earned", earned_stars)
+                        .with_arg("available", available_stars),
+                    ClassList::new_with_classes(["text"])
+                ),
                 (
                     Name::new("Level Select Menu"),
                     ClassList::new_with_classes(["dialog-menu", "level-select-menu"]),
                     Node::default(),
                     NavigableChildren::default(),
                     Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
-                        level_configs.into_iter().enumerate().for_each(
-                            |(index, (get_config, level_config))| {
-                                let mut button = spawner.spawn(button(level_config.name));
+                        level_buttons.into_iter().enumerate().for_each(
+                            |(index, (game_run, (name, stars)))| {
+                                let mut button = spawner.spawn((
+                                    Button,
+                                    Children::spawn_one((
+                                        Text::default(),
+                                        children![
+                                            (TextSpan::default(), name),
+                                            TextSpan::new(format!("  {stars}")),
+                                        ],
+                                    )),
+                                ));
                                 button.observe(
                                         move |
                                             _trigger: Trigger<ButtonActivate>,
@@ -42,7 +99,7 @@ pub fn spawn_level_select_menu(mut commands: Commands, asset_server: &AssetServe
                                             mut next_state: ResMut<NextState<AppState>>
                                         | {
                                             selected_mode.0 = Some(GameRunMode::SingleLevel);
-                                            commands.spawn(GameRun::new_single_level(get_config));
+                                            commands.spawn(game_run.clone());
                                             next_state.set(AppState::ResetGameplay);
                                         },
                                     );
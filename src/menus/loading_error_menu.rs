@@ -0,0 +1,67 @@
+use bevy::{ecs::spawn::SpawnWith, input_focus::AutoFocus, prelude::*};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+
+use crate::{
+    app_state::AppState,
+    localization::Localized,
+    menu::{ButtonActivate, NavigableChildren, localized_button},
+};
+
+pub fn spawn_loading_error_menu(
+    mut commands: Commands,
+    asset_server: &AssetServer,
+    failed_paths: &[String],
+) {
+    let failed = failed_paths.join("\n");
+    commands.spawn((
+        StateScoped(AppState::Loading),
+        ClassList::new_with_classes(["globally-center-children", "overlay"]),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        Children::spawn_one((
+            Node::default(),
+            ClassList::new_with_classes(["column", "loading"]),
+            children![
+                (
+                    Text::default(),
+                    Localized::new("loading-failed-heading"),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (
+                    Text::default(),
+                    ClassList::new_with_classes(["loading-errors"]),
+                    children![
+                        (TextSpan::default(), Localized::new("loading-failed-files")),
+                        TextSpan::new(format!("\n{failed}")),
+                    ]
+                ),
+                (
+                    Text::default(),
+                    Localized::new("loading-failed-text"),
+                    ClassList::new_with_classes(["text"])
+                ),
+                (
+                    Name::new("Loading Error Menu"),
+                    ClassList::new_with_classes(["dialog-menu"]),
+                    Node::default(),
+                    NavigableChildren::default(),
+                    Children::spawn(SpawnWith(|spawner: &mut ChildSpawner| {
+                        spawner.spawn((localized_button(Localized::new("menu-continue-anyway")), AutoFocus)).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut next_state: ResMut<NextState<AppState>>| {
+                                next_state.set(AppState::Title);
+                            },
+                        );
+                        spawner.spawn(localized_button(Localized::new("menu-quit"))).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut exit_event: EventWriter<AppExit>| {
+                                info!("Exiting");
+                                exit_event.write_default();
+                            },
+                        );
+                    })),
+                ),
+            ],
+        )),
+    ));
+}
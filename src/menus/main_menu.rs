@@ -4,7 +4,8 @@ use bevy_flair::style::components::{ClassList, NodeStyleSheet};
 use crate::{
     app_state::AppState,
     gameplay::game_run::{GameRunMode, SelectedGameRunMode},
-    menu::{ButtonActivate, NavigableChildren, button},
+    localization::Localized,
+    menu::{ButtonActivate, NavigableChildren, localized_button},
 };
 
 // Code here and in the related CSS file, originally from https://github.com/eckz/bevy_flair/blob/main/examples/game_menu.rs (MIT LICENSED)
@@ -41,10 +42,10 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: &AssetServer) {
                     spawner.spawn((
                         ClassList::new_with_classes(["main-menu-heading"]),
                         Node::default(),
-                        Children::spawn_one(Text::new("Main Menu")),
+                        Children::spawn_one((Text::default(), Localized::new("menu-main-menu"))),
                     ));
 
-                    spawner.spawn((button("Play"), AutoFocus)).observe(
+                    spawner.spawn((localized_button(Localized::new("menu-play")), AutoFocus)).observe(
                         |_trigger: Trigger<ButtonActivate>,
                          mut selected_mode: ResMut<SelectedGameRunMode>,
                          mut next_state: ResMut<NextState<AppState>>| {
@@ -53,7 +54,7 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: &AssetServer) {
                         },
                     );
 
-                    spawner.spawn(button("Training")).observe(
+                    spawner.spawn(localized_button(Localized::new("menu-training"))).observe(
                         |_trigger: Trigger<ButtonActivate>,
                          mut selected_mode: ResMut<SelectedGameRunMode>,
                          mut next_state: ResMut<NextState<AppState>>| {
@@ -62,7 +63,32 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: &AssetServer) {
                         },
                     );
 
-                    spawner.spawn(button("Level Select")).observe(
+                    spawner.spawn(localized_button(Localized::new("menu-time-attack"))).observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut selected_mode: ResMut<SelectedGameRunMode>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            selected_mode.0 = Some(GameRunMode::TimeAttack);
+                            next_state.set(AppState::ResetGameRun);
+                        },
+                    );
+
+                    spawner.spawn(localized_button(Localized::new("menu-endless"))).observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut selected_mode: ResMut<SelectedGameRunMode>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            selected_mode.0 = Some(GameRunMode::Endless);
+                            next_state.set(AppState::ResetGameRun);
+                        },
+                    );
+
+                    spawner.spawn(localized_button(Localized::new("menu-daily-challenge"))).observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::DailyChallenge);
+                        },
+                    );
+
+                    spawner.spawn(localized_button(Localized::new("menu-level-select"))).observe(
                         |_trigger: Trigger<ButtonActivate>,
                          mut selected_mode: ResMut<SelectedGameRunMode>,
                          mut next_state: ResMut<NextState<AppState>>| {
@@ -71,7 +97,35 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: &AssetServer) {
                         },
                     );
 
-                    spawner.spawn(button("Quit")).observe(
+                    spawner.spawn(localized_button(Localized::new("menu-custom-levels"))).observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::CustomLevels);
+                        },
+                    );
+
+                    spawner.spawn(localized_button(Localized::new("menu-level-editor"))).observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::Editor);
+                        },
+                    );
+
+                    spawner.spawn(localized_button(Localized::new("menu-high-scores"))).observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::HighScores);
+                        },
+                    );
+
+                    spawner.spawn(localized_button(Localized::new("menu-settings"))).observe(
+                        |_trigger: Trigger<ButtonActivate>,
+                         mut next_state: ResMut<NextState<AppState>>| {
+                            next_state.set(AppState::Settings);
+                        },
+                    );
+
+                    spawner.spawn(localized_button(Localized::new("menu-quit"))).observe(
                         |_trigger: Trigger<ButtonActivate>,
                          mut exit_event: EventWriter<AppExit>| {
                             info!("Exiting");
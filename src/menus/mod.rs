@@ -1,3 +1,8 @@
+pub mod custom_levels_menu;
+pub mod daily_challenge_menu;
+pub mod high_scores_menu;
 pub mod level_complete;
 pub mod level_select_menu;
+pub mod loading_error_menu;
 pub mod main_menu;
+pub mod settings_menu;
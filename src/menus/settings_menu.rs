@@ -0,0 +1,220 @@
+use bevy::{ecs::spawn::SpawnWith, input_focus::AutoFocus, prelude::*};
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
+
+use crate::{
+    app_state::AppState,
+    localization::{Localized, next_language},
+    menu::{ButtonActivate, NavigableChildren, localized_button},
+    settings::Settings,
+};
+
+fn on_off(value: bool) -> &'static str {
+    if value { "on" } else { "off" }
+}
+
+fn reduced_motion_label(settings: &Settings) -> Localized {
+    Localized::new("settings-reduced-motion").with_arg("value", on_off(settings.reduced_motion))
+}
+
+fn screen_flash_label(settings: &Settings) -> Localized {
+    Localized::new("settings-screen-flash").with_arg("value", on_off(settings.screen_flash))
+}
+
+fn particles_label(settings: &Settings) -> Localized {
+    Localized::new("settings-particles").with_arg("quality", settings.particles.key())
+}
+
+fn chain_preview_label(settings: &Settings) -> Localized {
+    Localized::new("settings-chain-preview").with_arg("value", on_off(settings.chain_preview))
+}
+
+fn danger_zones_label(settings: &Settings) -> Localized {
+    Localized::new("settings-danger-zones").with_arg("value", on_off(settings.danger_zones))
+}
+
+fn color_palette_label(settings: &Settings) -> Localized {
+    Localized::new("settings-color-palette").with_arg("palette", settings.color_palette.key())
+}
+
+fn wave_markers_label(settings: &Settings) -> Localized {
+    Localized::new("settings-wave-markers").with_arg("value", on_off(settings.wave_markers))
+}
+
+// Buttons are spawned with their label as the only child
+pub fn set_button_label(
+    button: Entity,
+    label: Localized,
+    children_q: &Query<&Children>,
+    localized_q: &mut Query<&mut Localized>,
+) {
+    let Some(mut localized) = children_q
+        .get(button)
+        .ok()
+        .and_then(|children| children.first())
+        .and_then(|child| localized_q.get_mut(*child).ok())
+    else {
+        warn!("Could not find settings button label");
+        return;
+    };
+    *localized = label;
+}
+
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    asset_server: &AssetServer,
+    settings: &Settings,
+) {
+    let reduced_motion = reduced_motion_label(settings);
+    let screen_flash = screen_flash_label(settings);
+    let particles = particles_label(settings);
+    let chain_preview = chain_preview_label(settings);
+    let danger_zones = danger_zones_label(settings);
+    let color_palette = color_palette_label(settings);
+    let wave_markers = wave_markers_label(settings);
+    commands.spawn((
+        StateScoped(AppState::Settings),
+        ClassList::new_with_classes(["globally-center-children", "overlay"]),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        Children::spawn_one((
+            Node::default(),
+            ClassList::new_with_classes(["column"]),
+            children![
+                (
+                    Text::default(),
+                    Localized::new("settings-heading"),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (
+                    Name::new("Settings Menu"),
+                    ClassList::new_with_classes(["dialog-menu"]),
+                    Node::default(),
+                    NavigableChildren::default(),
+                    Children::spawn(SpawnWith(move |spawner: &mut ChildSpawner| {
+                        spawner.spawn((localized_button(reduced_motion), AutoFocus)).observe(
+                            |trigger: Trigger<ButtonActivate>,
+                             mut settings: ResMut<Settings>,
+                             children_q: Query<&Children>,
+                             mut localized_q: Query<&mut Localized>| {
+                                settings.reduced_motion = !settings.reduced_motion;
+                                settings.save();
+                                set_button_label(
+                                    trigger.target(),
+                                    reduced_motion_label(&settings),
+                                    &children_q,
+                                    &mut localized_q,
+                                );
+                            },
+                        );
+                        spawner.spawn(localized_button(screen_flash)).observe(
+                            |trigger: Trigger<ButtonActivate>,
+                             mut settings: ResMut<Settings>,
+                             children_q: Query<&Children>,
+                             mut localized_q: Query<&mut Localized>| {
+                                settings.screen_flash = !settings.screen_flash;
+                                settings.save();
+                                set_button_label(
+                                    trigger.target(),
+                                    screen_flash_label(&settings),
+                                    &children_q,
+                                    &mut localized_q,
+                                );
+                            },
+                        );
+                        spawner.spawn(localized_button(particles)).observe(
+                            |trigger: Trigger<ButtonActivate>,
+                             mut settings: ResMut<Settings>,
+                             children_q: Query<&Children>,
+                             mut localized_q: Query<&mut Localized>| {
+                                settings.particles = settings.particles.next();
+                                settings.save();
+                                set_button_label(
+                                    trigger.target(),
+                                    particles_label(&settings),
+                                    &children_q,
+                                    &mut localized_q,
+                                );
+                            },
+                        );
+                        spawner.spawn(localized_button(chain_preview)).observe(
+                            |trigger: Trigger<ButtonActivate>,
+                             mut settings: ResMut<Settings>,
+                             children_q: Query<&Children>,
+                             mut localized_q: Query<&mut Localized>| {
+                                settings.chain_preview = !settings.chain_preview;
+                                settings.save();
+                                set_button_label(
+                                    trigger.target(),
+                                    chain_preview_label(&settings),
+                                    &children_q,
+                                    &mut localized_q,
+                                );
+                            },
+                        );
+                        spawner.spawn(localized_button(danger_zones)).observe(
+                            |trigger: Trigger<ButtonActivate>,
+                             mut settings: ResMut<Settings>,
+                             children_q: Query<&Children>,
+                             mut localized_q: Query<&mut Localized>| {
+                                settings.danger_zones = !settings.danger_zones;
+                                settings.save();
+                                set_button_label(
+                                    trigger.target(),
+                                    danger_zones_label(&settings),
+                                    &children_q,
+                                    &mut localized_q,
+                                );
+                            },
+                        );
+                        spawner.spawn(localized_button(color_palette)).observe(
+                            |trigger: Trigger<ButtonActivate>,
+                             mut settings: ResMut<Settings>,
+                             children_q: Query<&Children>,
+                             mut localized_q: Query<&mut Localized>| {
+                                settings.color_palette = settings.color_palette.next();
+                                settings.save();
+                                set_button_label(
+                                    trigger.target(),
+                                    color_palette_label(&settings),
+                                    &children_q,
+                                    &mut localized_q,
+                                );
+                            },
+                        );
+                        spawner.spawn(localized_button(wave_markers)).observe(
+                            |trigger: Trigger<ButtonActivate>,
+                             mut settings: ResMut<Settings>,
+                             children_q: Query<&Children>,
+                             mut localized_q: Query<&mut Localized>| {
+                                settings.wave_markers = !settings.wave_markers;
+                                settings.save();
+                                set_button_label(
+                                    trigger.target(),
+                                    wave_markers_label(&settings),
+                                    &children_q,
+                                    &mut localized_q,
+                                );
+                            },
+                        );
+                        spawner
+                            .spawn(localized_button(Localized::new("settings-language")))
+                            .observe(
+                                |_trigger: Trigger<ButtonActivate>,
+                                 mut settings: ResMut<Settings>| {
+                                    settings.language =
+                                        next_language(&settings.language).to_string();
+                                    settings.save();
+                                },
+                            );
+                        spawner.spawn(localized_button(Localized::new("menu-main-menu"))).observe(
+                            |_trigger: Trigger<ButtonActivate>,
+                             mut next_state: ResMut<NextState<AppState>>| {
+                                next_state.set(AppState::Title);
+                            },
+                        );
+                    })),
+                ),
+            ],
+        )),
+    ));
+}
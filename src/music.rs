@@ -0,0 +1,195 @@
+use bevy::{
+    audio::{AudioPlugin, Volume},
+    prelude::*,
+};
+
+use crate::{
+    app_state::AppState,
+    gameplay::{
+        explosion::{ExplosionChain, ExplosionChainEvent},
+        level::{LevelState, LevelStats},
+    },
+    sound::{PlaySound, SoundBus, SoundEffect, VolumeBuses},
+};
+
+// Seconds for a layer to fade all the way in or out
+const MUSIC_FADE_SECS: f32 = 1.5;
+// Chain waves before the lead is at full volume
+const CHAIN_WAVES_FOR_FULL_LEAD: f32 = 3.;
+// How quickly the chain build up falls away once no chain is running, in waves per second
+const CHAIN_INTENSITY_DECAY: f32 = 1.;
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<AudioPlugin>() {
+            return;
+        }
+        app.init_resource::<MusicStems>()
+            .init_resource::<ChainIntensity>()
+            .add_observer(build_chain_intensity)
+            .add_systems(OnEnter(LevelState::Complete), play_stinger)
+            .add_systems(
+                Update,
+                (
+                    spawn_music_layers.run_if(not(any_with_component::<MusicLayer>)),
+                    decay_chain_intensity,
+                    fade_music_layers,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// Stems are the same length and tempo so they stay in time while looping together
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MusicStem {
+    Pad,
+    Bass,
+    Drums,
+    Lead,
+}
+
+impl MusicStem {
+    fn all() -> [Self; 4] {
+        [Self::Pad, Self::Bass, Self::Drums, Self::Lead]
+    }
+    fn path(&self) -> &'static str {
+        match self {
+            Self::Pad => "audio/music_pad.wav",
+            Self::Bass => "audio/music_bass.wav",
+            Self::Drums => "audio/music_drums.wav",
+            Self::Lead => "audio/music_lead.wav",
+        }
+    }
+    // How loud the stem should be for where the player is
+    fn target(
+        &self,
+        app_state: AppState,
+        level_state: Option<LevelState>,
+        chain_intensity: f32,
+    ) -> f32 {
+        let layers = match (app_state, level_state) {
+            (AppState::Loading, _) => 0,
+            (AppState::ResetGameRun | AppState::GameRun | AppState::ResetGameplay, _) => 2,
+            (AppState::Gameplay, Some(LevelState::Loading | LevelState::Ready)) => 2,
+            (AppState::Gameplay, Some(LevelState::Playing)) => 3,
+            _ => 1,
+        };
+        let (needed, full) = match self {
+            Self::Pad => (1, 1.),
+            Self::Bass => (2, 1.),
+            Self::Drums => (3, 1.),
+            Self::Lead => (3, chain_intensity),
+        };
+        if layers >= needed { full } else { 0. }
+    }
+}
+
+#[derive(Resource, Debug)]
+struct MusicStems(Vec<(MusicStem, Handle<AudioSource>)>);
+
+impl FromWorld for MusicStems {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Self(
+            MusicStem::all()
+                .into_iter()
+                .map(|stem| (stem, asset_server.load(stem.path())))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Component, Debug)]
+struct MusicLayer {
+    stem: MusicStem,
+    level: f32,
+}
+
+// Builds up with each wave of an explosion chain
+#[derive(Resource, Debug, Default)]
+struct ChainIntensity(f32);
+
+impl ChainIntensity {
+    fn lead(&self) -> f32 {
+        (self.0 / CHAIN_WAVES_FOR_FULL_LEAD).min(1.)
+    }
+}
+
+// Waits for every stem so they all start playing on the same frame
+fn spawn_music_layers(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    stems: Res<MusicStems>,
+) {
+    if !stems
+        .0
+        .iter()
+        .all(|(_, handle)| asset_server.is_loaded_with_dependencies(handle))
+    {
+        return;
+    }
+    for (stem, handle) in &stems.0 {
+        commands.spawn((
+            Name::new(format!("Music {stem:?}")),
+            AudioPlayer::new(handle.clone()),
+            PlaybackSettings::LOOP.with_volume(Volume::Linear(0.)),
+            MusicLayer {
+                stem: *stem,
+                level: 0.,
+            },
+        ));
+    }
+}
+
+fn build_chain_intensity(
+    _trigger: Trigger<ExplosionChainEvent>,
+    mut intensity: ResMut<ChainIntensity>,
+) {
+    intensity.0 += 1.;
+}
+
+fn decay_chain_intensity(
+    time: Res<Time>,
+    mut intensity: ResMut<ChainIntensity>,
+    chain_q: Query<(), With<ExplosionChain>>,
+) {
+    if chain_q.is_empty() && intensity.0 > 0. {
+        intensity.0 = (intensity.0 - CHAIN_INTENSITY_DECAY * time.delta_secs()).max(0.);
+    }
+}
+
+// Layers fade toward their targets rather than switching, which cross-fades between states
+fn fade_music_layers(
+    time: Res<Time>,
+    buses: Res<VolumeBuses>,
+    intensity: Res<ChainIntensity>,
+    app_state: Res<State<AppState>>,
+    level_state: Option<Res<State<LevelState>>>,
+    mut layers_q: Query<(&mut MusicLayer, Option<&mut AudioSink>)>,
+) {
+    let step = time.delta_secs() / MUSIC_FADE_SECS;
+    let bus_volume = buses.volume(SoundBus::Music).to_linear();
+    for (mut layer, sink) in &mut layers_q {
+        let target = layer.stem.target(
+            *app_state.get(),
+            level_state.as_ref().map(|state| *state.get()),
+            intensity.lead(),
+        );
+        layer.level += (target - layer.level).clamp(-step, step);
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(bus_volume * layer.level));
+        }
+    }
+}
+
+fn play_stinger(level_stats: Single<&LevelStats>, mut sounds: EventWriter<PlaySound>) {
+    let stinger = if level_stats.success == Some(true) {
+        SoundEffect::StingerSuccess
+    } else {
+        SoundEffect::StingerFailure
+    };
+    sounds.write(PlaySound(stinger));
+}
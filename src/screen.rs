@@ -1,8 +1,11 @@
 use bevy::prelude::*;
 
 use crate::screens::{
+    custom_levels_screen::CustomLevelsScreenPlugin,
+    daily_challenge_screen::DailyChallengeScreenPlugin, high_scores_screen::HighScoresScreenPlugin,
     loading_screen::LoadingScreenPlugin, new_game_run::NewGameRunScreenPlugin,
-    ready_screen::ReadyScreenPlugin, title_screen::TitleScreenPlugin,
+    ready_screen::ReadyScreenPlugin, settings_screen::SettingsScreenPlugin,
+    title_screen::TitleScreenPlugin,
 };
 
 pub struct ScreenPlugin;
@@ -10,9 +13,13 @@ pub struct ScreenPlugin;
 impl Plugin for ScreenPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            CustomLevelsScreenPlugin,
+            DailyChallengeScreenPlugin,
+            HighScoresScreenPlugin,
             LoadingScreenPlugin,
             NewGameRunScreenPlugin,
             ReadyScreenPlugin,
+            SettingsScreenPlugin,
             TitleScreenPlugin,
         ));
     }
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::{
+    app_state::AppState,
+    gameplay::level_packs::{LevelPacks, PackProgress},
+    menus::custom_levels_menu,
+};
+
+pub struct CustomLevelsScreenPlugin;
+
+impl Plugin for CustomLevelsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::CustomLevels), spawn_custom_levels_menu);
+    }
+}
+
+fn spawn_custom_levels_menu(
+    commands: Commands,
+    asset_server: Res<AssetServer>,
+    level_packs: Res<LevelPacks>,
+    progress: Res<PackProgress>,
+) {
+    custom_levels_menu::spawn_custom_levels_menu(commands, &asset_server, &level_packs, &progress);
+}
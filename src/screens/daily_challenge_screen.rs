@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::{
+    app_state::AppState, gameplay::daily_challenge::DailyHistory, menus::daily_challenge_menu,
+};
+
+pub struct DailyChallengeScreenPlugin;
+
+impl Plugin for DailyChallengeScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::DailyChallenge),
+            spawn_daily_challenge_menu,
+        );
+    }
+}
+
+fn spawn_daily_challenge_menu(
+    commands: Commands,
+    asset_server: Res<AssetServer>,
+    history: Res<DailyHistory>,
+) {
+    daily_challenge_menu::spawn_daily_challenge_menu(commands, &asset_server, &history);
+}
@@ -0,0 +1,126 @@
+use bevy::{
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+
+use crate::{
+    app_state::AppState,
+    gameplay::high_scores::{HighScores, MAX_PLAYER_NAME_LENGTH, PlayerName},
+    loading::SceneHandles,
+    localization::{Localization, Localized},
+    menu::TextEntryFocus,
+    menus::high_scores_menu::{
+        self, HighScoreTableRows, HighScoreTableTitle, HighScoreTableView, PlayerNameText,
+        player_name_label,
+    },
+};
+
+pub struct HighScoresScreenPlugin;
+
+impl Plugin for HighScoresScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::HighScores), spawn_high_scores_menu)
+            .add_systems(OnExit(AppState::HighScores), finish_player_name_entry)
+            .add_systems(
+                Update,
+                (
+                    edit_player_name,
+                    update_player_name_text,
+                    update_high_score_table_text.run_if(
+                        resource_changed::<HighScoreTableView>.or(resource_changed::<Localization>),
+                    ),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::HighScores)),
+            );
+    }
+}
+
+fn spawn_high_scores_menu(
+    commands: Commands,
+    asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    high_scores: Res<HighScores>,
+    player_name: Res<PlayerName>,
+) {
+    high_scores_menu::spawn_high_scores_menu(
+        commands,
+        &asset_server,
+        &scenes,
+        &high_scores,
+        &player_name,
+    );
+}
+
+fn finish_player_name_entry(mut entry: ResMut<TextEntryFocus>, player_name: Res<PlayerName>) {
+    if entry.0 {
+        entry.0 = false;
+        player_name.save();
+    }
+}
+
+fn edit_player_name(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut entry: ResMut<TextEntryFocus>,
+    mut player_name: ResMut<PlayerName>,
+) {
+    // The Enter that started typing can still be unread, so typing starts from the next frame
+    if !entry.0 || entry.is_changed() {
+        keyboard_events.clear();
+        return;
+    }
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(characters) => {
+                for character in characters.chars().filter(|c| c.is_alphanumeric()) {
+                    if player_name.0.chars().count() < MAX_PLAYER_NAME_LENGTH {
+                        player_name.0.push(character.to_ascii_uppercase());
+                    }
+                }
+            }
+            Key::Backspace => {
+                player_name.0.pop();
+            }
+            // The menu ignores keys while the name has focus, so these are how typing ends
+            Key::Enter | Key::Escape => {
+                entry.0 = false;
+                player_name.save();
+                return;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn update_player_name_text(
+    entry: Res<TextEntryFocus>,
+    player_name: Res<PlayerName>,
+    mut label_q: Query<&mut Localized, With<PlayerNameText>>,
+) {
+    if !entry.is_changed() && !player_name.is_changed() {
+        return;
+    }
+    for mut label in &mut label_q {
+        *label = player_name_label(&player_name, entry.0);
+    }
+}
+
+fn update_high_score_table_text(
+    view: Res<HighScoreTableView>,
+    localization: Res<Localization>,
+    mut title_q: Query<&mut Localized, With<HighScoreTableTitle>>,
+    mut rows_q: Query<&mut Text, With<HighScoreTableRows>>,
+) {
+    for mut title in &mut title_q {
+        *title = view.title().clone();
+    }
+    for mut text in &mut rows_q {
+        text.0 = view.rows(&localization);
+    }
+}
@@ -1,15 +1,96 @@
 use bevy::prelude::*;
+use bevy_flair::style::components::{ClassList, NodeStyleSheet};
 
-use crate::app_state::AppState;
+use crate::{
+    app_state::AppState, levels::gltf_level::ImportedLevels, loading::AssetCollection,
+    localization::Localized, menus::loading_error_menu,
+};
 
 pub struct LoadingScreenPlugin;
 
 impl Plugin for LoadingScreenPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::Loading), check_load_status);
+        app.add_systems(OnEnter(AppState::Loading), spawn_loading_screen)
+            .add_systems(
+                Update,
+                check_load_status
+                    .run_if(in_state(AppState::Loading).and(any_with_component::<LoadingScreen>)),
+            );
     }
 }
 
-fn check_load_status(mut next_state: ResMut<NextState<AppState>>) {
-    next_state.set(AppState::Title);
+#[derive(Component, Debug)]
+struct LoadingScreen;
+
+#[derive(Component, Debug)]
+struct LoadingProgressBar;
+
+#[derive(Component, Debug)]
+struct LoadingProgressText;
+
+fn spawn_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        LoadingScreen,
+        StateScoped(AppState::Loading),
+        ClassList::new_with_classes(["globally-center-children", "overlay"]),
+        NodeStyleSheet::new(asset_server.load("styles/all.css")),
+        Node::default(),
+        Children::spawn_one((
+            Node::default(),
+            ClassList::new_with_classes(["column", "loading"]),
+            children![
+                (
+                    Text::default(),
+                    Localized::new("loading-heading"),
+                    ClassList::new_with_classes(["heading"])
+                ),
+                (
+                    Node::default(),
+                    ClassList::new_with_classes(["loading-bar"]),
+                    children![(
+                        LoadingProgressBar,
+                        // Only the width is set from code, Flair styles the rest
+                        Node {
+                            width: Val::Percent(0.),
+                            ..default()
+                        },
+                        ClassList::new_with_classes(["loading-bar-fill"]),
+                    )]
+                ),
+                (
+                    LoadingProgressText,
+                    Text::default(),
+                    ClassList::new_with_classes(["text"])
+                ),
+            ],
+        )),
+    ));
+}
+
+fn check_load_status(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    asset_collection: Res<AssetCollection>,
+    imported_levels: Res<ImportedLevels>,
+    loading_screen: Single<Entity, With<LoadingScreen>>,
+    mut progress_bar: Single<&mut Node, With<LoadingProgressBar>>,
+    mut progress_text: Single<&mut Text, With<LoadingProgressText>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let progress = asset_collection.progress(&asset_server);
+    progress_bar.width = Val::Percent(progress.fraction() * 100.);
+    progress_text.0 = format!("{}/{}", progress.loaded, progress.total);
+    // Level scenes still need their components read once they've loaded
+    if !progress.is_settled() || !imported_levels.is_settled() {
+        return;
+    }
+    if progress.failed.is_empty() {
+        next_state.set(AppState::Title);
+        return;
+    }
+    for path in &progress.failed {
+        warn!("Could not load {path}");
+    }
+    commands.entity(*loading_screen).despawn();
+    loading_error_menu::spawn_loading_error_menu(commands, &asset_server, &progress.failed);
 }
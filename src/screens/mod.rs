@@ -1,4 +1,8 @@
+pub mod custom_levels_screen;
+pub mod daily_challenge_screen;
+pub mod high_scores_screen;
 pub mod loading_screen;
 pub mod new_game_run;
 pub mod ready_screen;
+pub mod settings_screen;
 pub mod title_screen;
@@ -2,7 +2,14 @@ use bevy::prelude::*;
 
 use crate::{
     app_state::AppState,
-    gameplay::game_run::{GameRun, GameRunMode, SelectedGameRunMode},
+    gameplay::{
+        daily_challenge::{DailyAttempt, DailyHistory, today},
+        game_run::{GameRun, GameRunMode, SelectedGameRunMode},
+        rating::StarRecords,
+        time_attack::{TimeAttackBest, TimeAttackClock},
+    },
+    levels::gltf_level::ImportedLevels,
+    loading::SceneHandles,
     menus::level_select_menu::spawn_level_select_menu,
 };
 
@@ -17,6 +24,11 @@ impl Plugin for NewGameRunScreenPlugin {
 fn spawn_game_run(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    scenes: Res<SceneHandles>,
+    star_records: Res<StarRecords>,
+    time_attack_best: Res<TimeAttackBest>,
+    daily_history: Res<DailyHistory>,
+    imported_levels: Res<ImportedLevels>,
     mut next_state: ResMut<NextState<AppState>>,
     mut selected_game_run_mode: ResMut<SelectedGameRunMode>,
     game_run_q: Query<Entity, With<GameRun>>,
@@ -35,8 +47,42 @@ fn spawn_game_run(
             commands.spawn(GameRun::new_game());
             next_state.set(AppState::ResetGameplay);
         }
+        Some(GameRunMode::TimeAttack) => {
+            selected_game_run_mode.0 = None;
+            commands.spawn((
+                GameRun::new_time_attack(),
+                TimeAttackClock::new(&time_attack_best),
+            ));
+            next_state.set(AppState::ResetGameplay);
+        }
+        Some(GameRunMode::Endless) => {
+            selected_game_run_mode.0 = None;
+            let seed = chrono::Utc::now().timestamp_millis() as u64;
+            commands.spawn(GameRun::new_endless(seed));
+            next_state.set(AppState::ResetGameplay);
+        }
+        Some(GameRunMode::DailyChallenge) => {
+            selected_game_run_mode.0 = None;
+            let date = today();
+            commands.spawn((
+                GameRun::new_daily_challenge(date.clone()),
+                DailyAttempt::new(date, &daily_history),
+            ));
+            next_state.set(AppState::ResetGameplay);
+        }
         Some(GameRunMode::SingleLevel) => {
-            spawn_level_select_menu(commands, &asset_server);
+            spawn_level_select_menu(
+                commands,
+                &asset_server,
+                &scenes,
+                &star_records,
+                &imported_levels,
+            );
+        }
+        Some(GameRunMode::Playtest | GameRunMode::LevelPack) => {
+            // These are spawned by the editor and the custom levels menu with their levels
+            selected_game_run_mode.0 = None;
+            warn!("Playtest and level pack runs are started from their own menus");
         }
         None => {
             // TODO: do something so the user can continue
@@ -3,7 +3,10 @@ use std::time::Duration;
 use bevy::prelude::*;
 use bevy_flair::style::components::{ClassList, NodeStyleSheet};
 
-use crate::gameplay::level::LevelState;
+use crate::{
+    gameplay::{game_run::GameRun, level::LevelState},
+    localization::Localized,
+};
 
 pub struct ReadyScreenPlugin;
 
@@ -32,16 +35,42 @@ impl Countdown {
     fn is_complete(&self) -> bool {
         self.current >= self.count
     }
-    fn tick(&mut self, delta: Duration) {
+    fn remaining(&self) -> u8 {
+        self.count - self.current
+    }
+    // True when the count goes down
+    fn tick(&mut self, delta: Duration) -> bool {
         self.timer.tick(delta);
         if self.timer.just_finished() {
             self.current += 1;
             self.timer.reset();
+            true
+        } else {
+            false
         }
     }
 }
 
-fn spawn_countdown(mut commands: Commands, asset_server: Res<AssetServer>) {
+// Triggered as each number is shown, with 0 when play starts
+#[derive(Event, Clone, Debug, Reflect)]
+pub struct CountdownTickEvent {
+    pub remaining: u8,
+}
+
+fn spawn_countdown(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<LevelState>>,
+    game_run: Single<&GameRun>,
+) {
+    if game_run.mode().auto_advances() {
+        next_state.set(LevelState::Playing);
+        return;
+    }
+    let countdown = Countdown::new(1., 3);
+    commands.trigger(CountdownTickEvent {
+        remaining: countdown.remaining(),
+    });
     commands
         .spawn((
             StateScoped(LevelState::Ready),
@@ -51,11 +80,12 @@ fn spawn_countdown(mut commands: Commands, asset_server: Res<AssetServer>) {
         ))
         .with_children(|builder| {
             builder.spawn((
-                Text::new("Ready?"),
+                Text::default(),
+                Localized::new("ready"),
                 ClassList::new_with_classes(["countdown-label"]),
             ));
             builder.spawn((
-                Countdown::new(1., 3),
+                countdown,
                 Text::default(),
                 ClassList::new_with_classes(["countdown-time"]),
             ));
@@ -63,12 +93,17 @@ fn spawn_countdown(mut commands: Commands, asset_server: Res<AssetServer>) {
 }
 
 fn update_countdown(
+    mut commands: Commands,
     mut next_state: ResMut<NextState<LevelState>>,
     time: Res<Time>,
     mut countdown: Single<(&mut Countdown, &mut Text)>,
 ) {
-    countdown.0.tick(time.delta());
-    countdown.1.0 = format!("{:.0}", countdown.0.count - countdown.0.current);
+    if countdown.0.tick(time.delta()) {
+        commands.trigger(CountdownTickEvent {
+            remaining: countdown.0.remaining(),
+        });
+    }
+    countdown.1.0 = format!("{:.0}", countdown.0.remaining());
     if countdown.0.is_complete() {
         next_state.set(LevelState::Playing);
     }
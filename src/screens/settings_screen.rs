@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::{app_state::AppState, menus::settings_menu, settings::Settings};
+
+pub struct SettingsScreenPlugin;
+
+impl Plugin for SettingsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Settings), spawn_settings_menu);
+    }
+}
+
+fn spawn_settings_menu(
+    commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+) {
+    settings_menu::spawn_settings_menu(commands, &asset_server, &settings);
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{color_palette::ColorPalette, localization::DEFAULT_LANGUAGE, storage};
+
+const SETTINGS_KEY: &str = "settings";
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<Settings>(SETTINGS_KEY));
+    }
+}
+
+// Player preferences changed from the Settings menu
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // Turns off camera shake, hit-stop and screen flashes
+    pub reduced_motion: bool,
+    pub screen_flash: bool,
+    pub particles: ParticleQuality,
+    // Marks which enemies a running chain will blow up next, and when
+    pub chain_preview: bool,
+    // Outlines how far explosions will reach, always shown in training
+    pub danger_zones: bool,
+    // A locale with a file under assets/locales, like "en-US"
+    pub language: String,
+    pub color_palette: ColorPalette,
+    // Shapes on defenders showing which wave they are in
+    pub wave_markers: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            reduced_motion: false,
+            screen_flash: true,
+            particles: ParticleQuality::High,
+            chain_preview: true,
+            danger_zones: false,
+            language: DEFAULT_LANGUAGE.to_string(),
+            color_palette: ColorPalette::Standard,
+            wave_markers: false,
+        }
+    }
+}
+
+impl Settings {
+    pub fn save(&self) {
+        storage::save(SETTINGS_KEY, self);
+    }
+    pub fn shows_screen_flash(&self) -> bool {
+        self.screen_flash && !self.reduced_motion
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParticleQuality {
+    Off,
+    Low,
+    #[default]
+    High,
+}
+
+impl ParticleQuality {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::High => Self::Low,
+            Self::Low => Self::Off,
+            Self::Off => Self::High,
+        }
+    }
+    // Message id argument, see settings-particles in assets/locales
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Low => "low",
+            Self::High => "high",
+        }
+    }
+    // Share of the full particle count that gets spawned
+    pub fn density(&self) -> f32 {
+        match self {
+            Self::Off => 0.,
+            Self::Low => 0.35,
+            Self::High => 1.,
+        }
+    }
+}
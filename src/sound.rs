@@ -0,0 +1,257 @@
+use bevy::{
+    audio::{AudioPlugin, Volume},
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::{
+        enemy::{EnemyCollisionEvent, EnemyDestroyedEvent},
+        explosion::ExplosionChainEvent,
+        player::{PlayerDestroyedEvent, PlayerFiredEvent},
+    },
+    loading::ExplosionKind,
+    menu::ButtonActivate,
+    screens::ready_screen::CountdownTickEvent,
+    storage,
+};
+
+const VOLUME_BUSES_KEY: &str = "volume";
+// Longer than any effect, so a sound still around after this never got an output to play on
+const SOUND_TIMEOUT_SECS: f32 = 3.;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(storage::load::<VolumeBuses>(VOLUME_BUSES_KEY))
+            .add_event::<PlaySound>();
+        // Headless builds and tests run without Bevy's audio, so there's nothing to play through
+        if !app.is_plugin_added::<AudioPlugin>() {
+            return;
+        }
+        app.init_resource::<SoundHandles>()
+            .add_observer(play_fire)
+            .add_observer(play_enemy_hit)
+            .add_observer(play_enemy_destroyed)
+            .add_observer(play_chain_wave)
+            .add_observer(play_player_destroyed)
+            .add_observer(play_countdown_tick)
+            .add_observer(play_button_activate)
+            .add_systems(
+                Update,
+                (
+                    play_sounds,
+                    apply_bus_volumes.run_if(resource_changed::<VolumeBuses>),
+                    drop_unplayed_sounds,
+                ),
+            );
+    }
+}
+
+// Every playing sound belongs to one bus, which is scaled by the master volume
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SoundBus {
+    Effects,
+    Interface,
+    Music,
+}
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumeBuses {
+    pub master: f32,
+    pub effects: f32,
+    pub interface: f32,
+    pub music: f32,
+}
+
+impl Default for VolumeBuses {
+    fn default() -> Self {
+        Self {
+            master: 1.,
+            effects: 0.8,
+            interface: 0.8,
+            music: 0.6,
+        }
+    }
+}
+
+impl VolumeBuses {
+    pub fn bus(&self, bus: SoundBus) -> f32 {
+        match bus {
+            SoundBus::Effects => self.effects,
+            SoundBus::Interface => self.interface,
+            SoundBus::Music => self.music,
+        }
+    }
+    pub fn set_bus(&mut self, bus: SoundBus, volume: f32) {
+        let volume = volume.clamp(0., 1.);
+        match bus {
+            SoundBus::Effects => self.effects = volume,
+            SoundBus::Interface => self.interface = volume,
+            SoundBus::Music => self.music = volume,
+        }
+    }
+    // What a sound on the bus actually plays at
+    pub fn volume(&self, bus: SoundBus) -> Volume {
+        Volume::Linear(self.master.clamp(0., 1.) * self.bus(bus))
+    }
+    pub fn save(&self) {
+        storage::save(VOLUME_BUSES_KEY, self);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    Fire,
+    Hit,
+    Destroy(ExplosionKind),
+    ChainWave,
+    CountdownTick,
+    CountdownGo,
+    Button,
+    StingerSuccess,
+    StingerFailure,
+}
+
+impl SoundEffect {
+    fn all() -> [Self; 12] {
+        [
+            Self::Fire,
+            Self::Hit,
+            Self::Destroy(ExplosionKind::Base),
+            Self::Destroy(ExplosionKind::Defender),
+            Self::Destroy(ExplosionKind::Land),
+            Self::Destroy(ExplosionKind::Player),
+            Self::ChainWave,
+            Self::CountdownTick,
+            Self::CountdownGo,
+            Self::Button,
+            Self::StingerSuccess,
+            Self::StingerFailure,
+        ]
+    }
+    fn path(&self) -> &'static str {
+        match self {
+            Self::Fire => "audio/fire.wav",
+            Self::Hit => "audio/hit.wav",
+            Self::Destroy(ExplosionKind::Base) => "audio/destroy_base.wav",
+            Self::Destroy(ExplosionKind::Defender) => "audio/destroy_defender.wav",
+            Self::Destroy(ExplosionKind::Land) => "audio/destroy_land.wav",
+            Self::Destroy(ExplosionKind::Player) => "audio/player_destroyed.wav",
+            Self::ChainWave => "audio/chain_wave.wav",
+            Self::CountdownTick => "audio/countdown_tick.wav",
+            Self::CountdownGo => "audio/countdown_go.wav",
+            Self::Button => "audio/button.wav",
+            Self::StingerSuccess => "audio/stinger_success.wav",
+            Self::StingerFailure => "audio/stinger_failure.wav",
+        }
+    }
+    fn bus(&self) -> SoundBus {
+        match self {
+            Self::CountdownTick | Self::CountdownGo | Self::Button => SoundBus::Interface,
+            Self::StingerSuccess | Self::StingerFailure => SoundBus::Music,
+            _ => SoundBus::Effects,
+        }
+    }
+}
+
+#[derive(Resource, Debug)]
+struct SoundHandles(HashMap<SoundEffect, Handle<AudioSource>>);
+
+impl FromWorld for SoundHandles {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Self(
+            SoundEffect::all()
+                .into_iter()
+                .map(|effect| (effect, asset_server.load(effect.path())))
+                .collect(),
+        )
+    }
+}
+
+// Anything can ask for a sound, repeats in the same frame are only played once
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlaySound(pub SoundEffect);
+
+#[derive(Component, Debug)]
+struct SoundTimeout(Timer);
+
+fn play_sounds(
+    mut commands: Commands,
+    mut events: EventReader<PlaySound>,
+    handles: Res<SoundHandles>,
+    buses: Res<VolumeBuses>,
+) {
+    let effects = events.read().map(|event| event.0).collect::<HashSet<_>>();
+    for effect in effects {
+        let bus = effect.bus();
+        commands.spawn((
+            Name::new(format!("Sound {effect:?}")),
+            AudioPlayer::new(handles.0[&effect].clone()),
+            PlaybackSettings::DESPAWN.with_volume(buses.volume(bus)),
+            bus,
+            SoundTimeout(Timer::from_seconds(SOUND_TIMEOUT_SECS, TimerMode::Once)),
+        ));
+    }
+}
+
+fn apply_bus_volumes(buses: Res<VolumeBuses>, mut sinks_q: Query<(&mut AudioSink, &SoundBus)>) {
+    for (mut sink, bus) in &mut sinks_q {
+        sink.set_volume(buses.volume(*bus));
+    }
+}
+
+// Without an output device Bevy never starts these, so they'd pile up instead of despawning
+fn drop_unplayed_sounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut sounds_q: Query<(Entity, &mut SoundTimeout), Without<AudioSink>>,
+) {
+    for (entity, mut timeout) in &mut sounds_q {
+        if timeout.0.tick(time.delta()).just_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn play_fire(_trigger: Trigger<PlayerFiredEvent>, mut sounds: EventWriter<PlaySound>) {
+    sounds.write(PlaySound(SoundEffect::Fire));
+}
+
+fn play_enemy_hit(_trigger: Trigger<EnemyCollisionEvent>, mut sounds: EventWriter<PlaySound>) {
+    sounds.write(PlaySound(SoundEffect::Hit));
+}
+
+fn play_enemy_destroyed(trigger: Trigger<EnemyDestroyedEvent>, mut sounds: EventWriter<PlaySound>) {
+    if let Some(kind) = ExplosionKind::of_class(trigger.event().class) {
+        sounds.write(PlaySound(SoundEffect::Destroy(kind)));
+    }
+}
+
+fn play_chain_wave(_trigger: Trigger<ExplosionChainEvent>, mut sounds: EventWriter<PlaySound>) {
+    sounds.write(PlaySound(SoundEffect::ChainWave));
+}
+
+fn play_player_destroyed(
+    _trigger: Trigger<PlayerDestroyedEvent>,
+    mut sounds: EventWriter<PlaySound>,
+) {
+    sounds.write(PlaySound(SoundEffect::Destroy(ExplosionKind::Player)));
+}
+
+fn play_countdown_tick(trigger: Trigger<CountdownTickEvent>, mut sounds: EventWriter<PlaySound>) {
+    let effect = if trigger.event().remaining == 0 {
+        SoundEffect::CountdownGo
+    } else {
+        SoundEffect::CountdownTick
+    };
+    sounds.write(PlaySound(effect));
+}
+
+fn play_button_activate(_trigger: Trigger<ButtonActivate>, mut sounds: EventWriter<PlaySound>) {
+    sounds.write(PlaySound(SoundEffect::Button));
+}
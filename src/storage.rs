@@ -0,0 +1,109 @@
+use bevy::{asset::ron, prelude::*};
+use serde::{Serialize, de::DeserializeOwned};
+
+// Saved data lives in the user's data directory natively and in local storage on the web.
+// Anything missing or unreadable falls back to its default so a bad file never stops the game.
+
+pub fn load<T: DeserializeOwned + Default>(key: &str) -> T {
+    let Some(contents) = read(key) else {
+        return T::default();
+    };
+    ron::from_str(&contents).unwrap_or_else(|err| {
+        warn!("Could not parse saved {key}: {err}");
+        T::default()
+    })
+}
+
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => write(key, &contents),
+        Err(err) => warn!("Could not serialize {key}: {err}"),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn data_dir() -> Option<std::path::PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("maxx_obliterate"))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read(key: &str) -> Option<String> {
+    let path = data_dir()?.join(format!("{key}.ron"));
+    std::fs::read_to_string(path).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write(key: &str, contents: &str) {
+    let Some(dir) = data_dir() else {
+        warn!("No data directory available - {key} will not be saved");
+        return;
+    };
+    // Keys can contain a folder such as levels/my_level
+    let path = dir.join(format!("{key}.ron"));
+    if let Err(err) = std::fs::create_dir_all(path.parent().unwrap_or(&dir))
+        .and_then(|_| std::fs::write(&path, contents))
+    {
+        warn!("Could not save {key}: {err}");
+    }
+}
+
+// Every saved key inside a folder, such as levels/my_level for the levels folder
+#[cfg(not(target_arch = "wasm32"))]
+pub fn keys_in(folder: &str) -> Vec<String> {
+    let Some(entries) = data_dir().and_then(|dir| std::fs::read_dir(dir.join(folder)).ok()) else {
+        return Vec::new();
+    };
+    let mut keys = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ron"))
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_str()?;
+            Some(format!("{folder}/{stem}"))
+        })
+        .collect::<Vec<_>>();
+    keys.sort();
+    keys
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read(key: &str) -> Option<String> {
+    local_storage()?
+        .get_item(&format!("maxx_obliterate.{key}"))
+        .ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write(key: &str, contents: &str) {
+    let Some(storage) = local_storage() else {
+        warn!("No local storage available - {key} will not be saved");
+        return;
+    };
+    if storage
+        .set_item(&format!("maxx_obliterate.{key}"), contents)
+        .is_err()
+    {
+        warn!("Could not save {key}");
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn keys_in(folder: &str) -> Vec<String> {
+    let Some(storage) = local_storage() else {
+        return Vec::new();
+    };
+    let prefix = format!("maxx_obliterate.{folder}/");
+    let length = storage.length().unwrap_or_default();
+    let mut keys = (0..length)
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .filter(|key| key.starts_with(&prefix))
+        .filter_map(|key| key.strip_prefix("maxx_obliterate.").map(str::to_string))
+        .collect::<Vec<_>>();
+    keys.sort();
+    keys
+}
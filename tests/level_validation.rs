@@ -0,0 +1,20 @@
+use bevy::{prelude::*, scene::ScenePlugin};
+use maxx_obliterate::{levels::validation::validate_registered_levels, loading::SceneHandles};
+
+#[test]
+fn registered_levels_are_valid() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScenePlugin));
+    let scenes = SceneHandles::new(app.world().resource::<AssetServer>());
+    let mut errors = Vec::new();
+    for (name, problems) in validate_registered_levels(&scenes) {
+        for problem in problems {
+            if problem.is_error() {
+                errors.push(format!("{name}: {problem}"));
+            } else {
+                println!("{name}: {problem}");
+            }
+        }
+    }
+    assert!(errors.is_empty(), "Invalid levels:\n{}", errors.join("\n"));
+}
@@ -0,0 +1,157 @@
+use std::{collections::BTreeSet, path::Path};
+
+use bevy::{prelude::*, scene::ScenePlugin};
+use maxx_obliterate::{
+    gameplay::{enemy::EnemyClass, game_run::GameRun},
+    loading::SceneHandles,
+    localization::{DEFAULT_LANGUAGE, Localization, Localized, languages},
+};
+
+#[test]
+fn built_in_levels_and_enemy_classes_are_translated() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), ScenePlugin));
+    let scenes = SceneHandles::new(app.world().resource::<AssetServer>());
+    let localization = Localization::new(DEFAULT_LANGUAGE);
+    let mut missing = Vec::new();
+    for get_config in GameRun::game_levels()
+        .into_iter()
+        .chain(GameRun::training_levels())
+    {
+        let level_config = get_config(&scenes);
+        if !level_config
+            .message_id
+            .is_some_and(|message_id| localization.has_message(message_id))
+        {
+            missing.push(level_config.name);
+        }
+    }
+    for class in EnemyClass::in_order() {
+        if !localization.has_message(class.message_id()) {
+            missing.push(class.to_string());
+        }
+    }
+    assert!(missing.is_empty(), "Untranslated:\n{}", missing.join("\n"));
+}
+
+#[test]
+fn every_language_names_itself() {
+    for language in languages() {
+        let localization = Localization::new(language);
+        assert!(localization.has_message("language-name"), "{language}");
+    }
+}
+
+#[test]
+fn counts_pick_a_plural_form() {
+    let localization = Localization::new(DEFAULT_LANGUAGE);
+    let destroyed = |total: u32| {
+        localization.format(
+            &Localized::new("level-complete-enemies-destroyed")
+                .with_arg("destroyed", 1u32)
+                .with_arg("total", total),
+        )
+    };
+    assert_eq!(destroyed(1), "Level Enemy Destroyed: 1/1");
+    assert_eq!(destroyed(3), "Level Enemies Destroyed: 1/3");
+}
+
+#[test]
+fn missing_languages_fall_back() {
+    // Same language in another region
+    assert_eq!(Localization::new("es-MX").text("ready"), "¿Listo?");
+    assert_eq!(Localization::new("fr-FR").text("ready"), "Ready?");
+    let custom = Localized::level_name(None, "My Level");
+    assert_eq!(Localization::new("es-ES").format(&custom), "My Level");
+}
+
+#[test]
+fn custom_level_names_are_not_translated() {
+    let localization = Localization::new("es-ES");
+    // Each of these matches a built in level or a menu message once slugged
+    for name in ["Defenders", "Land", "Goal", "Select Heading"] {
+        assert_eq!(
+            localization.format(&Localized::level_name(None, name)),
+            name
+        );
+    }
+    assert_eq!(
+        localization.format(&Localized::level_notes(None, "My notes")),
+        "My notes"
+    );
+}
+
+const LOCALE_FILES: [(&str, &str); 2] = [
+    ("en-US", include_str!("../assets/locales/en-US/main.ftl")),
+    ("es-ES", include_str!("../assets/locales/es-ES/main.ftl")),
+];
+
+// Message ids, with attributes as "message.attribute" like Localization looks them up
+fn message_ids(source: &str) -> BTreeSet<String> {
+    let mut ids = BTreeSet::new();
+    let mut message = String::new();
+    for line in source.lines() {
+        let Some((name, _)) = line.split_once('=') else {
+            continue;
+        };
+        if line.starts_with(|c: char| c.is_ascii_lowercase()) {
+            message = name.trim().to_string();
+            ids.insert(message.clone());
+        } else if let Some(attribute) = name.trim().strip_prefix('.') {
+            ids.insert(format!("{message}.{attribute}"));
+        }
+    }
+    ids
+}
+
+#[test]
+fn every_language_has_the_same_messages() {
+    let (default_language, default_source) = LOCALE_FILES[0];
+    let expected = message_ids(default_source);
+    for (language, source) in LOCALE_FILES {
+        let ids = message_ids(source);
+        let missing = expected.difference(&ids).cloned().collect::<Vec<_>>();
+        let extra = ids.difference(&expected).cloned().collect::<Vec<_>>();
+        assert!(
+            missing.is_empty() && extra.is_empty(),
+            "{language} is missing {missing:?} and has {extra:?} that {default_language} doesn't"
+        );
+    }
+    assert_eq!(
+        LOCALE_FILES.map(|(language, _)| language).to_vec(),
+        languages().collect::<Vec<_>>()
+    );
+}
+
+fn localized_ids_in(path: &Path, ids: &mut Vec<String>) {
+    for entry in std::fs::read_dir(path).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            localized_ids_in(&path, ids);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            let source = std::fs::read_to_string(&path).unwrap();
+            for rest in source.split("Localized::new(\"").skip(1) {
+                if let Some((id, _)) = rest.split_once('"') {
+                    ids.push(id.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn messages_used_in_code_exist() {
+    let mut ids = Vec::new();
+    localized_ids_in(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut ids);
+    assert!(!ids.is_empty());
+    let localization = Localization::new(DEFAULT_LANGUAGE);
+    let missing = ids
+        .into_iter()
+        .filter(|id| !localization.has_message(id))
+        .collect::<Vec<_>>();
+    assert!(
+        missing.is_empty(),
+        "Missing messages:\n{}",
+        missing.join("\n")
+    );
+}